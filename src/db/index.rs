@@ -0,0 +1,38 @@
+use crate::prelude::*;
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+};
+
+type KeyphraseIndex = Vec<(Vec<(String, String)>, String)>;
+
+pub fn load_keyphrase_index(index_path: &Path, hash: &str) -> Result<Option<KeyphraseIndex>> {
+    if !index_path.exists() {
+        tracing::info!("Keyphrase index not found. Compiling keyphrase index.");
+        return Ok(None);
+    }
+
+    let reader = BufReader::new(File::open(index_path)?);
+    let (index_hash, index): (String, KeyphraseIndex) = match bincode::deserialize_from(reader) {
+        Ok(index) => index,
+        Err(e) => {
+            tracing::error!("Failed to read keyphrase index: {e}");
+            return Ok(None);
+        }
+    };
+
+    if index_hash != hash {
+        tracing::info!("Keyphrase index is outdated. Recompiling keyphrase index.");
+        return Ok(None);
+    }
+
+    Ok(Some(index))
+}
+
+pub fn save_keyphrase_index(index_path: &Path, hash: &str, index: &KeyphraseIndex) -> Result<()> {
+    let writer = BufWriter::new(File::create(index_path)?);
+    bincode::serialize_into(writer, &(hash, index))?;
+
+    Ok(())
+}
@@ -1,225 +1,1234 @@
-use super::util::get_db_pool;
+use super::policy::{load_geonames_policy, GeonamesPolicy};
+use super::util::{add_column_if_missing, get_db_pool};
 use crate::prelude::*;
-use crate::scrape::scraper::forbes400::get_largest_billionaires_map;
-use crate::scrape::scraper::wikidata::{region_code_to_figures, verify_codes};
-use crate::scrape::scraper::wikipedia::get_private_enterprises_map;
-use crate::service::zip_service::{zip_from_url, zip_to_txt};
-use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use crate::scrape::iso3166::COUNTRIES;
+use crate::scrape::region::{
+    get_manual_keyphrases, KEYPHRASE_ACRONYM_MAP, KEYPHRASE_REGION_MAP, KEYPHRASE_SUBREGION_MAP,
+};
+use crate::scrape::scraper::billionaires::{get_billionaires, Billionaire};
+use crate::scrape::scraper::sanctions::get_sanctioned_map;
+use crate::scrape::scraper::wikidata::{
+    get_figures, get_position_holders, get_sparql_endpoint, report_iso_codes, Figure,
+};
+use crate::scrape::scraper::wikipedia::{get_table_entries, load_table_sources};
+use crate::service::var_service::{get_integer_var, get_optional_var};
+use crate::service::zip_service::{for_each_line, zip_from_url};
+use anyhow::anyhow;
 use reqwest::Client;
-use sqlx::sqlite::SqliteQueryResult;
-use sqlx::Executor;
-use std::{collections::HashSet, io::BufRead, path::Path};
+use serde_json::{from_str, json, to_string_pretty, Value};
+use sqlx::{Executor, Row, SqlitePool};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs::{read_to_string, remove_file, write},
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
 use unidecode::unidecode;
 
-struct Region {
+// Subregion code, latitude, and longitude of a keyphrase.
+type Location = (Option<String>, Option<f64>, Option<f64>);
+
+// Start and end of an office holder's term in UNIX seconds, where no end means still in office.
+type Validity = (Option<i64>, Option<i64>);
+
+const SOURCES: [&str; 6] = [
+    "geonames",
+    "wikidata",
+    "positions",
+    "forbes",
+    "wikipedia",
+    "sanctions",
+];
+
+#[derive(Clone)]
+struct Keyphrase {
     region_code: String,
-    keyphrases: String,
+    phrase: String,
+    category: String,
+    source_id: String,
+    subregion_code: Option<String>,
+    coordinates: Option<(f64, f64)>,
+    validity: Validity,
+    provenance: Option<String>, // Positions an office holder's keyphrase comes from
+}
+
+// Local copies of the automated sources, read instead of downloading them.
+pub struct SourcePaths {
+    pub geonames: Option<String>,
+    pub alternate_names: Option<String>,
+    pub wikidata: Option<String>,
+    pub positions: Option<String>,
+    pub forbes: Option<String>,
+    pub wikipedia: Option<String>,
+    pub ofac_sdn: Option<String>,
+    pub ofac_add: Option<String>,
+    pub un_sanctions: Option<String>,
+    pub eu_sanctions: Option<String>,
+}
+
+async fn get_source_paths() -> Result<SourcePaths> {
+    Ok(SourcePaths {
+        geonames: get_optional_var("GEONAMES_PATH").await?,
+        alternate_names: get_optional_var("GEONAMES_ALT_NAMES_PATH").await?,
+        wikidata: get_optional_var("WIKIDATA_PATH").await?,
+        positions: get_optional_var("WIKIDATA_POSITIONS_PATH").await?,
+        forbes: get_optional_var("FORBES_PATH").await?,
+        wikipedia: get_optional_var("WIKIPEDIA_PATH").await?,
+        ofac_sdn: get_optional_var("OFAC_SDN_PATH").await?,
+        ofac_add: get_optional_var("OFAC_ADD_PATH").await?,
+        un_sanctions: get_optional_var("UN_SANCTIONS_PATH").await?,
+        eu_sanctions: get_optional_var("EU_SANCTIONS_PATH").await?,
+    })
 }
 
 pub async fn gen_keyphrase_db(docker_volume: &str) -> Result<()> {
+    refresh_keyphrase_db(docker_volume, &[]).await
+}
+
+pub async fn refresh_keyphrase_db(docker_volume: &str, forced_sources: &[String]) -> Result<()> {
+    let paths = get_source_paths().await?;
+    refresh_sources(docker_volume, forced_sources, &paths).await
+}
+
+// Refreshes every source whose refresh interval has elapsed, along with any explicitly forced sources.
+async fn refresh_sources(
+    docker_volume: &str,
+    forced_sources: &[String],
+    paths: &SourcePaths,
+) -> Result<()> {
     let db_path = format!("{}/region_db.sqlite", docker_volume);
     let db_path = Path::new(&db_path);
-    if db_path.exists() {
-        tracing::info!("region_db.sqlite found. Skipping keyphrase database generation.");
-        return Ok(());
+    let pool = get_db_pool(db_path).await?;
+    create_keyphrase_db(&pool).await?;
+
+    let client = Client::new();
+    for source in SOURCES {
+        let last_refresh = get_last_refresh(&pool, source).await?;
+        let forced = forced_sources.iter().any(|forced| forced == source);
+        if !forced && !is_refresh_due(source, last_refresh).await? {
+            tracing::info!("{source} keyphrases are up to date. Skipping refresh.");
+            continue;
+        }
+
+        tracing::info!("Refreshing {source} keyphrases.");
+        let keyphrases = match source {
+            "geonames" => {
+                get_geonames_keyphrases(&client, docker_volume, last_refresh, paths).await
+            }
+            "wikidata" => get_wikidata_keyphrases(&client, docker_volume, paths).await,
+            "positions" => get_positions_keyphrases(&client, docker_volume, paths).await,
+            "forbes" => get_forbes_keyphrases(&client, &pool, docker_volume, paths).await,
+            "wikipedia" => get_wikipedia_keyphrases(&client, paths).await,
+            "sanctions" => get_sanctions_keyphrases(&client, paths).await,
+            _ => continue,
+        };
+        // A failed source keeps its existing keyphrases and stays due, so it never stops scraping.
+        let refreshed = match keyphrases {
+            Ok(keyphrases) => refresh_source(&pool, source, keyphrases).await,
+            Err(err) => Err(err),
+        };
+        if let Err(err) = refreshed {
+            tracing::error!("Failed to refresh {source} keyphrases: {err}");
+        }
     }
+    remove_legacy_keyphrases(&pool).await?;
+
+    Ok(())
+}
+
+async fn is_refresh_due(source: &str, last_refresh: Option<i64>) -> Result<bool> {
+    let Some(last_refresh) = last_refresh else {
+        return Ok(true);
+    };
+
+    let refresh_days = match source {
+        "geonames" => get_integer_var("GEONAMES_REFRESH_DAYS", 30).await?,
+        "wikidata" => get_integer_var("WIKIDATA_REFRESH_DAYS", 7).await?,
+        "positions" => get_integer_var("POSITIONS_REFRESH_DAYS", 7).await?,
+        "forbes" => get_integer_var("FORBES_REFRESH_DAYS", 7).await?,
+        "sanctions" => get_integer_var("SANCTIONS_REFRESH_DAYS", 7).await?,
+        _ => get_integer_var("WIKIPEDIA_REFRESH_DAYS", 30).await?,
+    };
+
+    Ok(get_now()? - last_refresh >= refresh_days * 86400)
+}
+
+async fn get_geonames_keyphrases(
+    client: &Client,
+    docker_volume: &str,
+    last_refresh: Option<i64>,
+    paths: &SourcePaths,
+) -> Result<Vec<Keyphrase>> {
+    let geonames_path = get_geonames_path(client, docker_volume, last_refresh, paths).await?;
+    let policy = load_geonames_policy(get_optional_var("GEONAMES_POLICY_PATH").await?.as_deref())?;
+    let mut keyphrases = Vec::new();
+    tracing::info!("Reading {geonames_path}.");
+    for_each_line(&geonames_path, |line| {
+        push_geonames_keyphrases(line, &policy, &mut keyphrases)
+    })
+    .await?;
+    let geoname_ids = get_geoname_ids(&keyphrases);
+    let alternate_names =
+        get_alternate_names(client, docker_volume, last_refresh, &geoname_ids, paths).await?;
+    push_alternate_names(&mut keyphrases, &alternate_names);
 
+    Ok(keyphrases)
+}
+
+// Dry run of a GeoNames policy: logs the keyphrases each region would gain or lose compared to the current policy.
+pub async fn report_geonames_policy(docker_volume: &str, policy_path: &str) -> Result<()> {
     let client = Client::new();
-    let all_countries_path = format!("{}/allCountries.txt", docker_volume);
-    let all_countries_path = Path::new(&all_countries_path);
-    if all_countries_path.exists() {
-        tracing::info!("allCountries.txt found. Skipping download and decompression.");
-    } else {
-        let zip_path = format!("{}/allCountries.zip", docker_volume);
-        if !Path::new(&zip_path).exists() {
-            tracing::info!("allCountries.zip not found. Downloading allCountries.zip.");
-            zip_from_url(
-                &client,
-                "https://download.geonames.org/export/dump/allCountries.zip",
-                &zip_path,
-            )
-            .await?;
+    let paths = get_source_paths().await?;
+    let geonames_path = get_geonames_path(&client, docker_volume, None, &paths).await?;
+    let current_policy =
+        load_geonames_policy(get_optional_var("GEONAMES_POLICY_PATH").await?.as_deref())?;
+    let proposed_policy = load_geonames_policy(Some(policy_path))?;
+
+    let mut current = Vec::new();
+    let mut proposed = Vec::new();
+    tracing::info!("Reading {geonames_path}.");
+    for_each_line(&geonames_path, |line| {
+        push_geonames_keyphrases(line, &current_policy, &mut current);
+        push_geonames_keyphrases(line, &proposed_policy, &mut proposed);
+    })
+    .await?;
+    let mut geoname_ids = get_geoname_ids(&current);
+    geoname_ids.extend(get_geoname_ids(&proposed));
+    let alternate_names =
+        get_alternate_names(&client, docker_volume, None, &geoname_ids, &paths).await?;
+    push_alternate_names(&mut current, &alternate_names);
+    push_alternate_names(&mut proposed, &alternate_names);
+
+    let current: HashSet<(String, String)> = current.iter().map(get_keyphrase_key).collect();
+    let proposed: HashSet<(String, String)> = proposed.iter().map(get_keyphrase_key).collect();
+    let mut diff: BTreeMap<&str, (Vec<&str>, Vec<&str>)> = BTreeMap::new();
+    for (region_code, phrase) in proposed.difference(&current) {
+        diff.entry(region_code).or_default().0.push(phrase);
+    }
+    for (region_code, phrase) in current.difference(&proposed) {
+        diff.entry(region_code).or_default().1.push(phrase);
+    }
+
+    for (region_code, (gained, lost)) in diff.iter_mut() {
+        gained.sort();
+        lost.sort();
+        tracing::info!(
+            "{region_code}: +{} {:?}, -{} {:?}",
+            gained.len(),
+            gained,
+            lost.len(),
+            lost
+        );
+    }
+    tracing::info!(
+        "Policy {policy_path} would change {} regions: {} keyphrases now, {} with the policy.",
+        diff.len(),
+        current.len(),
+        proposed.len()
+    );
+
+    Ok(())
+}
+
+async fn get_geonames_path(
+    client: &Client,
+    docker_volume: &str,
+    last_refresh: Option<i64>,
+    paths: &SourcePaths,
+) -> Result<String> {
+    match &paths.geonames {
+        Some(local_path) => Ok(local_path.clone()),
+        None => download_geonames(client, docker_volume, "allCountries", last_refresh).await,
+    }
+}
+
+// Alternate names of the given GeoNames entries in the languages of GEONAMES_ALT_NAME_LANGUAGES, from alternateNamesV2,
+// whose names carry ISO language tags. Only entries the policy includes are kept, since alternateNamesV2 names millions
+// of others. A local GEONAMES_PATH without GEONAMES_ALT_NAMES_PATH goes without them, so that local builds never
+// download.
+async fn get_alternate_names(
+    client: &Client,
+    docker_volume: &str,
+    last_refresh: Option<i64>,
+    geoname_ids: &HashSet<String>,
+    paths: &SourcePaths,
+) -> Result<HashMap<String, Vec<String>>> {
+    let mut alternate_names: HashMap<String, Vec<String>> = HashMap::new();
+    let min_length: usize = get_integer_var("GEONAMES_ALT_NAME_MIN_LENGTH", 4)
+        .await?
+        .try_into()?;
+    if min_length == 0 {
+        return Ok(alternate_names);
+    }
+
+    let alternate_names_path = match &paths.alternate_names {
+        Some(local_path) => local_path.clone(),
+        None if paths.geonames.is_some() => {
+            tracing::info!(
+                "GEONAMES_ALT_NAMES_PATH is not set. Skipping GeoNames alternate names."
+            );
+            return Ok(alternate_names);
+        }
+        None => download_geonames(client, docker_volume, "alternateNamesV2", last_refresh).await?,
+    };
+    let languages: Vec<String> = get_optional_var("GEONAMES_ALT_NAME_LANGUAGES")
+        .await?
+        .unwrap_or("en".to_string())
+        .split(',')
+        .map(|language| language.trim().to_lowercase())
+        .filter(|language| !language.is_empty())
+        .collect();
+
+    tracing::info!("Reading {alternate_names_path}.");
+    for_each_line(&alternate_names_path, |line| {
+        // alternateNameId, geonameid, isolanguage, alternate name, isPreferredName, isShortName, isColloquial, ...
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [_, geoname_id, language, name, _, _, is_colloquial, ..] = fields[..] else {
+            return;
+        };
+
+        if !geoname_ids.contains(geoname_id) {
+            return;
+        }
+
+        // Names without lowercase letters are abbreviations, and colloquial names are nicknames such as "Big Apple".
+        let name = name.trim();
+        if is_colloquial == "1"
+            || name.chars().count() < min_length
+            || !name.chars().any(|c| c.is_lowercase())
+            || !languages.iter().any(|allowed| allowed == language)
+        {
+            return;
+        }
+
+        alternate_names
+            .entry(geoname_id.to_string())
+            .or_default()
+            .push(unidecode(name));
+    })
+    .await?;
+
+    Ok(alternate_names)
+}
+
+fn get_geoname_ids(keyphrases: &[Keyphrase]) -> HashSet<String> {
+    keyphrases
+        .iter()
+        .map(|keyphrase| keyphrase.source_id.clone())
+        .collect()
+}
+
+// Adds a keyphrase for each alternate name of the GeoNames entries in keyphrases, which hold their ASCII names.
+fn push_alternate_names(
+    keyphrases: &mut Vec<Keyphrase>,
+    alternate_names: &HashMap<String, Vec<String>>,
+) {
+    let mut alternate_keyphrases = Vec::new();
+    for keyphrase in keyphrases.iter() {
+        for name in alternate_names
+            .get(&keyphrase.source_id)
+            .into_iter()
+            .flatten()
+        {
+            alternate_keyphrases.push(Keyphrase {
+                phrase: name.clone(),
+                ..keyphrase.clone()
+            });
         }
-        tracing::info!("Decompressing allCountries.zip.");
-        zip_to_txt(&zip_path).await?;
     }
+    keyphrases.extend(alternate_keyphrases);
+}
 
-    let pool = get_db_pool(db_path).await?;
+fn push_geonames_keyphrases(line: &str, policy: &GeonamesPolicy, keyphrases: &mut Vec<Keyphrase>) {
+    let fields: Vec<&str> = line.split('\t').collect();
+    let [geoname_id, _, ascii_name, _, latitude, longitude, feature_class, feature_code, region_code, _, admin1_code, _, _, _, population, ..] =
+        fields[..]
+    else {
+        return;
+    };
+
+    let population = match population.parse::<u32>() {
+        Ok(population) => population,
+        Err(_) => return,
+    };
+
+    if !policy.includes(
+        region_code,
+        feature_class,
+        feature_code,
+        population,
+        ascii_name,
+    ) {
+        return;
+    }
+
+    let subregion_code = get_subregion_code(region_code, admin1_code);
+    let coordinates = match (latitude.parse::<f64>(), longitude.parse::<f64>()) {
+        (Ok(latitude), Ok(longitude)) => Some((latitude, longitude)),
+        _ => None,
+    };
+    keyphrases.push(Keyphrase {
+        region_code: region_code.to_string(),
+        phrase: ascii_name.to_string(),
+        category: "place".to_string(),
+        source_id: geoname_id.to_string(),
+        subregion_code,
+        coordinates,
+        validity: (None, None),
+        provenance: None,
+    });
+}
+
+// Subregion codes join the country code and the GeoNames admin1 code, such as "ua-14". They are not ISO 3166-2 codes,
+// since GeoNames keeps FIPS-style admin1 codes for many countries: Crimea is "ua-11" rather than UA-43, and Kyiv
+// "ua-12" rather than UA-30. "00" is GeoNames' code for entries that are not assigned to an admin1 division.
+pub fn get_subregion_code(region_code: &str, admin1_code: &str) -> Option<String> {
+    match admin1_code {
+        "" | "00" => None,
+        admin1_code => Some(format!("{}-{}", region_code, admin1_code).to_lowercase()),
+    }
+}
+
+// Downloads a GeoNames dump such as "allCountries" into the volume. A refresh revalidates an existing dump and only
+// downloads it again once GeoNames has replaced it. GeoNames publishes no checksums, so a download is checked against
+// its announced size, and its entries against their CRC32 as they are read.
+async fn download_geonames(
+    client: &Client,
+    docker_volume: &str,
+    dump: &str,
+    last_refresh: Option<i64>,
+) -> Result<String> {
+    let txt_path = format!("{}/{}.txt", docker_volume, dump);
+    if Path::new(&txt_path).exists() {
+        // Left behind by versions that decompressed the dump to disk before reading it.
+        remove_file(&txt_path)?;
+    }
+
+    let zip_path = format!("{}/{}.zip", docker_volume, dump);
+    if last_refresh.is_some() || !Path::new(&zip_path).exists() {
+        tracing::info!("Checking for a new {dump}.zip.");
+        zip_from_url(
+            client,
+            &format!("https://download.geonames.org/export/dump/{dump}.zip"),
+            &zip_path,
+        )
+        .await?;
+    }
+
+    Ok(zip_path)
+}
+
+async fn get_wikidata_keyphrases(
+    client: &Client,
+    docker_volume: &str,
+    paths: &SourcePaths,
+) -> Result<Vec<Keyphrase>> {
+    let export: Option<Value> = match &paths.wikidata {
+        Some(export_path) => Some(from_str(&read_to_string(export_path)?)?),
+        None => None,
+    };
+    let endpoint = get_sparql_endpoint().await?;
+    let figures = get_figures(
+        client,
+        &endpoint,
+        export.as_ref(),
+        docker_volume,
+        &get_region_codes(),
+    )
+    .await?;
+
+    Ok(get_figure_keyphrases(figures, "figure"))
+}
+
+async fn get_positions_keyphrases(
+    client: &Client,
+    docker_volume: &str,
+    paths: &SourcePaths,
+) -> Result<Vec<Keyphrase>> {
+    let export: Option<Value> = match &paths.positions {
+        Some(export_path) => Some(from_str(&read_to_string(export_path)?)?),
+        None => None,
+    };
+    let endpoint = get_sparql_endpoint().await?;
+    let holders = get_position_holders(
+        client,
+        &endpoint,
+        export.as_ref(),
+        docker_volume,
+        &get_region_codes(),
+    )
+    .await?;
+
+    Ok(get_figure_keyphrases(holders, "official"))
+}
+
+fn get_figure_keyphrases(figures: Vec<Figure>, category: &str) -> Vec<Keyphrase> {
+    figures
+        .into_iter()
+        .map(|figure| Keyphrase {
+            region_code: figure.region_code,
+            phrase: figure.name,
+            category: category.to_string(),
+            source_id: figure.figure_id,
+            subregion_code: None,
+            coordinates: None,
+            validity: (figure.valid_from, figure.valid_until),
+            provenance: Some(figure.provenance).filter(|provenance| !provenance.is_empty()),
+        })
+        .collect()
+}
+
+async fn get_forbes_keyphrases(
+    client: &Client,
+    pool: &SqlitePool,
+    docker_volume: &str,
+    paths: &SourcePaths,
+) -> Result<Vec<Keyphrase>> {
+    let billionaires = get_billionaires(client, docker_volume, paths.forbes.as_deref()).await?;
+    store_billionaires(pool, &billionaires).await?;
+    Ok(billionaires
+        .into_iter()
+        .map(|billionaire| Keyphrase {
+            region_code: billionaire.region_code,
+            phrase: billionaire.name,
+            category: "billionaire".to_string(),
+            source_id: billionaire.id,
+            subregion_code: None,
+            coordinates: None,
+            validity: (None, None),
+            provenance: None,
+        })
+        .collect())
+}
+
+// Net worth and companies of the billionaires behind the forbes keyphrases, kept like them when none were retrieved.
+async fn store_billionaires(pool: &SqlitePool, billionaires: &[Billionaire]) -> Result<()> {
+    if billionaires.is_empty() {
+        return Ok(());
+    }
+
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM billionaires")
+        .execute(&mut *tx)
+        .await?;
+    for billionaire in billionaires {
+        sqlx::query(
+            "INSERT OR REPLACE INTO billionaires (source_id, region_code, name, net_worth, companies)
+            VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(&billionaire.id)
+        .bind(&billionaire.region_code)
+        .bind(&billionaire.name)
+        .bind(billionaire.net_worth)
+        .bind(billionaire.companies.join(", "))
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+
+    Ok(())
+}
+
+// Entities of the Wikipedia tables in WIKIPEDIA_TABLES_PATH, each under its source's category.
+async fn get_wikipedia_keyphrases(client: &Client, paths: &SourcePaths) -> Result<Vec<Keyphrase>> {
+    let mut keyphrases = Vec::new();
+    let table_sources = load_table_sources(
+        get_optional_var("WIKIPEDIA_TABLES_PATH").await?.as_deref(),
+        paths.wikipedia.as_deref(),
+    )?;
+    for table_source in table_sources {
+        for entry in get_table_entries(client, &table_source).await? {
+            keyphrases.push(Keyphrase {
+                region_code: entry.region_code,
+                phrase: entry.name,
+                category: table_source.category.clone(),
+                source_id: entry.source_id,
+                subregion_code: None,
+                coordinates: None,
+                validity: (None, None),
+                provenance: None,
+            });
+        }
+    }
+
+    Ok(keyphrases)
+}
+
+async fn get_sanctions_keyphrases(client: &Client, paths: &SourcePaths) -> Result<Vec<Keyphrase>> {
+    let mut keyphrases = Vec::new();
+    for (region_code, designations) in get_sanctioned_map(client, paths).await? {
+        for (id, name) in designations {
+            keyphrases.push(Keyphrase {
+                region_code: region_code.to_string(),
+                phrase: name,
+                category: "sanctioned".to_string(),
+                source_id: id,
+                subregion_code: None,
+                coordinates: None,
+                validity: (None, None),
+                provenance: None,
+            });
+        }
+    }
+
+    Ok(keyphrases)
+}
+
+async fn create_keyphrase_db(pool: &SqlitePool) -> Result<()> {
+    pool.execute(
+        "CREATE TABLE IF NOT EXISTS keyphrases (
+            region_code TEXT NOT NULL,
+            phrase TEXT NOT NULL,
+            category TEXT NOT NULL,
+            source TEXT NOT NULL,
+            source_id TEXT,
+            subregion_code TEXT,
+            latitude REAL,
+            longitude REAL,
+            valid_from INTEGER,
+            valid_until INTEGER,
+            provenance TEXT,
+            added_at INTEGER NOT NULL,
+            PRIMARY KEY (region_code, phrase, source)
+        )",
+    )
+    .await?;
+    add_column_if_missing(pool, "keyphrases", "subregion_code", "TEXT").await?;
+    add_column_if_missing(pool, "keyphrases", "latitude", "REAL").await?;
+    add_column_if_missing(pool, "keyphrases", "longitude", "REAL").await?;
+    add_column_if_missing(pool, "keyphrases", "valid_from", "INTEGER").await?;
+    add_column_if_missing(pool, "keyphrases", "valid_until", "INTEGER").await?;
+    add_column_if_missing(pool, "keyphrases", "provenance", "TEXT").await?;
+    pool.execute("CREATE INDEX IF NOT EXISTS keyphrases_source ON keyphrases (source)")
+        .await?;
+    pool.execute(
+        "CREATE TABLE IF NOT EXISTS billionaires (
+            source_id TEXT NOT NULL,
+            region_code TEXT NOT NULL,
+            name TEXT NOT NULL,
+            net_worth REAL NOT NULL,
+            companies TEXT NOT NULL,
+            PRIMARY KEY (source_id, region_code)
+        )",
+    )
+    .await?;
     pool.execute(
-        "CREATE TABLE IF NOT EXISTS regions (
-            region_code TEXT PRIMARY KEY,
-            keyphrases TEXT
+        "CREATE TABLE IF NOT EXISTS source_refreshes (
+            source TEXT PRIMARY KEY,
+            refreshed_at INTEGER NOT NULL,
+            changed_at INTEGER
         )",
     )
     .await?;
+    add_column_if_missing(pool, "source_refreshes", "changed_at", "INTEGER").await?;
+    migrate_regions(pool).await?;
 
-    let reader = std::io::BufReader::new(std::fs::File::open(all_countries_path)?);
-    for line in reader.lines() {
-        let line = line?;
-        let mut fields = line.split('\t');
-        let ascii_name = match fields.nth(2) {
-            Some(ascii_name) => ascii_name,
-            None => continue,
-        };
+    Ok(())
+}
 
-        let feature_class = match fields.nth(3) {
-            Some(feature_class) => feature_class,
-            None => continue,
-        };
+// Moves the comma-joined keyphrases of the superseded regions table into the keyphrases table as "legacy" ones, so a
+// database from before it still has keyphrases while its automated sources cannot be refreshed.
+async fn migrate_regions(pool: &SqlitePool) -> Result<()> {
+    let mut tx = pool.begin().await?;
+    let has_regions: Option<String> = sqlx::query_scalar(
+        "SELECT name FROM sqlite_master WHERE type = 'table' AND name = 'regions'",
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+    if has_regions.is_none() {
+        return Ok(());
+    }
 
-        let feature_code = match fields.next() {
-            Some(feature_code) => feature_code,
-            None => continue,
-        };
+    let rows: Vec<(String, Option<String>)> =
+        sqlx::query_as("SELECT region_code, keyphrases FROM regions")
+            .fetch_all(&mut *tx)
+            .await?;
+    let now = get_now()?;
+    let mut migrated = 0;
+    for (region_code, keyphrases) in &rows {
+        for phrase in keyphrases.as_deref().unwrap_or_default().split(',') {
+            let phrase = unidecode(&phrase.to_lowercase()).trim().to_string();
+            if phrase.is_empty() {
+                continue;
+            }
 
-        if feature_code.contains('H') {
-            continue;
+            migrated += sqlx::query(
+                "INSERT OR IGNORE INTO keyphrases (region_code, phrase, category, source, added_at)
+                VALUES (?, ?, 'legacy', 'legacy', ?)",
+            )
+            .bind(region_code.to_lowercase())
+            .bind(phrase)
+            .bind(now)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
         }
+    }
+    sqlx::query("DROP TABLE regions").execute(&mut *tx).await?;
+    tx.commit().await?;
+    tracing::info!(
+        "Migrated {migrated} keyphrases of {} regions from the regions table.",
+        rows.len()
+    );
 
-        let region_code = match fields.next() {
-            Some(region_code) => region_code,
-            None => continue,
-        };
+    Ok(())
+}
 
-        if region_code == "MZ" && ascii_name.contains("aza") {
+// Legacy keyphrases are only needed until every automated source has been refreshed.
+async fn remove_legacy_keyphrases(pool: &SqlitePool) -> Result<()> {
+    for source in SOURCES {
+        if get_last_refresh(pool, source).await?.is_none() {
+            return Ok(());
+        }
+    }
+
+    let removed = sqlx::query("DELETE FROM keyphrases WHERE source = 'legacy'")
+        .execute(pool)
+        .await?
+        .rows_affected();
+    if removed > 0 {
+        tracing::info!("Removed {removed} legacy keyphrases now that every source is refreshed.");
+    }
+
+    Ok(())
+}
+
+async fn get_last_refresh(pool: &SqlitePool, source: &str) -> Result<Option<i64>> {
+    let row = sqlx::query("SELECT refreshed_at FROM source_refreshes WHERE source = ?")
+        .bind(source)
+        .fetch_optional(pool)
+        .await?;
+
+    match row {
+        Some(row) => Ok(Some(row.try_get::<i64, _>(0)?)),
+        None => Ok(None),
+    }
+}
+
+// Replaces a source's keyphrases with the given ones, only touching rows that were added or removed.
+async fn refresh_source(pool: &SqlitePool, source: &str, keyphrases: Vec<Keyphrase>) -> Result<()> {
+    let mut refreshed: HashMap<(String, String), Keyphrase> = HashMap::new();
+    for keyphrase in keyphrases {
+        let key = get_keyphrase_key(&keyphrase);
+        if key.1.is_empty() {
             continue;
-        } // "Gaza" is a better keyphrase for Palestine than Mozambique.
-
-        let population = match fields.nth(5) {
-            Some(population) => match population.parse::<u32>() {
-                Ok(population) => population,
-                Err(_) => continue,
-            },
-            None => continue,
-        };
+        }
 
-        match feature_class {
-            "A" => {
-                if population < 490000 {
-                    continue;
+        match refreshed.get_mut(&key) {
+            // A name shared by several places of a country cannot tell them apart.
+            Some(existing) if existing.source_id != keyphrase.source_id => {
+                if existing.subregion_code != keyphrase.subregion_code {
+                    existing.subregion_code = None;
                 }
+                existing.coordinates = None;
+            }
+            Some(_) => (),
+            None => {
+                refreshed.insert(key, keyphrase);
             }
-            "P" => match feature_code {
-                "PPLC" => (),
-                _ => {
-                    if population < 290000 {
-                        continue;
-                    }
-                }
-            },
-            _ => continue,
         }
+    }
 
-        update_region(
-            &pool,
-            Region {
-                region_code: region_code.to_string(),
-                keyphrases: ascii_name.to_string(),
-            },
+    let existing: HashMap<(String, String), (Location, Validity, Option<String>)> = sqlx::query(
+        "SELECT region_code, phrase, subregion_code, latitude, longitude, valid_from, valid_until,
+        provenance FROM keyphrases WHERE source = ?",
+    )
+    .bind(source)
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .map(|row| {
+        (
+            (row.get(0), row.get(1)),
+            (
+                (row.get(2), row.get(3), row.get(4)),
+                (row.get(5), row.get(6)),
+                row.get(7),
+            ),
+        )
+    })
+    .collect();
+    if refreshed.is_empty() && !existing.is_empty() {
+        tracing::error!("No {source} keyphrases were retrieved. Keeping existing keyphrases.");
+        return Ok(());
+    }
+
+    let removed: Vec<&(String, String)> = existing
+        .keys()
+        .filter(|key| !refreshed.contains_key(*key))
+        .collect();
+    let added: Vec<(&(String, String), &Keyphrase)> = refreshed
+        .iter()
+        .filter(|(key, _)| !existing.contains_key(*key))
+        .collect();
+    let relocated: Vec<(&(String, String), &Keyphrase)> = refreshed
+        .iter()
+        .filter(|(key, keyphrase)| {
+            existing
+                .get(*key)
+                .is_some_and(|(location, _, _)| *location != get_location(keyphrase))
+        })
+        .collect();
+    let revalidated: Vec<(&(String, String), &Keyphrase)> = refreshed
+        .iter()
+        .filter(|(key, keyphrase)| {
+            existing.get(*key).is_some_and(|(_, validity, provenance)| {
+                *validity != keyphrase.validity || *provenance != keyphrase.provenance
+            })
+        })
+        .collect();
+
+    let now = get_now()?;
+    let mut tx = pool.begin().await?;
+    for (region_code, phrase) in &removed {
+        sqlx::query("DELETE FROM keyphrases WHERE region_code = ? AND phrase = ? AND source = ?")
+            .bind(region_code)
+            .bind(phrase)
+            .bind(source)
+            .execute(&mut *tx)
+            .await?;
+    }
+    for ((region_code, phrase), keyphrase) in &added {
+        sqlx::query(
+            "INSERT OR IGNORE INTO keyphrases (region_code, phrase, category, source, source_id, subregion_code, latitude, longitude, valid_from, valid_until, provenance, added_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )
+        .bind(region_code)
+        .bind(phrase)
+        .bind(&keyphrase.category)
+        .bind(source)
+        .bind(&keyphrase.source_id)
+        .bind(&keyphrase.subregion_code)
+        .bind(keyphrase.coordinates.map(|(latitude, _)| latitude))
+        .bind(keyphrase.coordinates.map(|(_, longitude)| longitude))
+        .bind(keyphrase.validity.0)
+        .bind(keyphrase.validity.1)
+        .bind(&keyphrase.provenance)
+        .bind(now)
+        .execute(&mut *tx)
         .await?;
     }
+    for ((region_code, phrase), keyphrase) in &relocated {
+        let (subregion_code, latitude, longitude) = get_location(keyphrase);
+        sqlx::query(
+            "UPDATE keyphrases SET subregion_code = ?, latitude = ?, longitude = ?
+            WHERE region_code = ? AND phrase = ? AND source = ?",
+        )
+        .bind(subregion_code)
+        .bind(latitude)
+        .bind(longitude)
+        .bind(region_code)
+        .bind(phrase)
+        .bind(source)
+        .execute(&mut *tx)
+        .await?;
+    }
+    for ((region_code, phrase), keyphrase) in &revalidated {
+        sqlx::query(
+            "UPDATE keyphrases SET valid_from = ?, valid_until = ?, provenance = ?
+            WHERE region_code = ? AND phrase = ? AND source = ?",
+        )
+        .bind(keyphrase.validity.0)
+        .bind(keyphrase.validity.1)
+        .bind(&keyphrase.provenance)
+        .bind(region_code)
+        .bind(phrase)
+        .bind(source)
+        .execute(&mut *tx)
+        .await?;
+    }
+    // The compiled maps are only rebuilt when a refresh changed any keyphrase (see get_keyphrase_version).
+    let changed = !removed.is_empty()
+        || !added.is_empty()
+        || !relocated.is_empty()
+        || !revalidated.is_empty();
+    sqlx::query(
+        "INSERT INTO source_refreshes (source, refreshed_at, changed_at) VALUES (?, ?, ?)
+        ON CONFLICT (source) DO UPDATE SET refreshed_at = excluded.refreshed_at,
+        changed_at = COALESCE(excluded.changed_at, changed_at)",
+    )
+    .bind(source)
+    .bind(now)
+    .bind(changed.then_some(now))
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
 
-    verify_codes().await;
-    let region_codes = vec![
-        "ad", "ae", "af", "ag", "ai", "al", "am", "ao", "aq", "ar", "as", "at", "au", "aw", "ax",
-        "az", "ba", "bb", "bd", "be", "bf", "bg", "bh", "bi", "bj", "bl", "bm", "bn", "bo", "bq",
-        "br", "bs", "bt", "bv", "bw", "by", "bz", "ca", "cc", "cd", "cf", "cg", "ch", "ci", "ck",
-        "cl", "cm", "cn", "co", "cr", "cu", "cv", "cw", "cx", "cy", "cz", "de", "dj", "dk", "dm",
-        "do", "dz", "ec", "ee", "eg", "eh", "er", "es", "et", "fi", "fj", "fk", "fm", "fo", "fr",
-        "ga", "gb", "gd", "ge", "gf", "gg", "gh", "gi", "gl", "gm", "gn", "gp", "gq", "gr", "gs",
-        "gt", "gu", "gw", "gy", "hk", "hm", "hn", "hr", "ht", "hu", "id", "ie", "il", "im", "in",
-        "io", "iq", "ir", "is", "it", "je", "jm", "jo", "jp", "ke", "kg", "kh", "ki", "km", "kn",
-        "kp", "kr", "kw", "ky", "kz", "la", "lb", "lc", "li", "lk", "lr", "ls", "lt", "lu", "lv",
-        "ly", "ma", "mc", "md", "me", "mf", "mg", "mh", "mk", "ml", "mm", "mn", "mo", "mp", "mq",
-        "mr", "ms", "mt", "mu", "mv", "mw", "mx", "my", "mz", "na", "nc", "ne", "nf", "ng", "ni",
-        "nl", "no", "np", "nr", "nu", "nz", "om", "pa", "pe", "pf", "pg", "ph", "pk", "pl", "pm",
-        "pn", "pr", "ps", "pt", "pw", "py", "qa", "re", "ro", "rs", "ru", "rw", "sa", "sb", "sc",
-        "sd", "se", "sg", "sh", "si", "sj", "sk", "sl", "sm", "sn", "so", "sr", "ss", "st", "sv",
-        "sx", "sy", "sz", "tc", "td", "tf", "tg", "th", "tj", "tk", "tl", "tm", "tn", "to", "tr",
-        "tt", "tv", "tw", "tz", "ua", "ug", "um", "us", "uy", "uz", "va", "vc", "ve", "vg", "vi",
-        "vn", "vu", "wf", "ws", "xk", "ye", "yt", "za", "zm", "zw",
-    ];
-    for region_code in region_codes {
-        let heads_of_state = region_code_to_figures(&client, region_code).await?;
-        for head_of_state in heads_of_state {
-            update_region(
-                &pool,
-                Region {
+    log_refresh_diff(source, &added, &removed);
+    if !relocated.is_empty() {
+        tracing::info!(
+            "Updated locations of {} {source} keyphrases.",
+            relocated.len()
+        );
+    }
+    if !revalidated.is_empty() {
+        tracing::info!(
+            "Updated terms and provenance of {} {source} keyphrases.",
+            revalidated.len()
+        );
+    }
+
+    Ok(())
+}
+
+fn get_location(keyphrase: &Keyphrase) -> Location {
+    (
+        keyphrase.subregion_code.clone(),
+        keyphrase.coordinates.map(|(latitude, _)| latitude),
+        keyphrase.coordinates.map(|(_, longitude)| longitude),
+    )
+}
+
+fn get_keyphrase_key(keyphrase: &Keyphrase) -> (String, String) {
+    (
+        keyphrase.region_code.to_lowercase(),
+        unidecode(&keyphrase.phrase.to_lowercase())
+            .trim()
+            .to_string(),
+    )
+}
+
+fn log_refresh_diff(
+    source: &str,
+    added: &[(&(String, String), &Keyphrase)],
+    removed: &[&(String, String)],
+) {
+    let mut diff: BTreeMap<&str, (Vec<&str>, Vec<&str>)> = BTreeMap::new();
+    for ((region_code, phrase), _) in added {
+        diff.entry(region_code).or_default().0.push(phrase);
+    }
+    for (region_code, phrase) in removed {
+        diff.entry(region_code).or_default().1.push(phrase);
+    }
+
+    for (region_code, (added, removed)) in &diff {
+        tracing::info!(
+            "{source} keyphrases for {region_code}: +{} {:?}, -{} {:?}",
+            added.len(),
+            added,
+            removed.len(),
+            removed
+        );
+    }
+    tracing::info!(
+        "Refreshed {source} keyphrases: {} added, {} removed across {} regions.",
+        added.len(),
+        removed.len(),
+        diff.len()
+    );
+}
+
+// Exports manual and stored keyphrases of every region, grouped by region code and sorted for diffing, in the form
+// import accepts.
+pub async fn export_keyphrases(docker_volume: &str, export_path: &str) -> Result<()> {
+    let db_path = format!("{}/region_db.sqlite", docker_volume);
+    let db_path = Path::new(&db_path);
+    let pool = get_db_pool(db_path).await?;
+    create_keyphrase_db(&pool).await?;
+
+    let mut regions: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+    for (region_code, keyphrases) in get_manual_keyphrases() {
+        let region = regions.entry(region_code.to_string()).or_default();
+        for (category, phrase) in keyphrases {
+            region.push(json!({
+                "phrase": phrase,
+                "category": category,
+                "source": "manual",
+            }));
+        }
+    }
+
+    let rows = sqlx::query(
+        "SELECT region_code, phrase, category, source, source_id, subregion_code, latitude, longitude,
+        valid_from, valid_until, provenance FROM keyphrases ORDER BY region_code, category, phrase",
+    )
+    .fetch_all(&pool)
+    .await?;
+    for row in &rows {
+        regions.entry(row.try_get(0)?).or_default().push(json!({
+            "phrase": row.try_get::<String, _>(1)?,
+            "category": row.try_get::<String, _>(2)?,
+            "source": row.try_get::<String, _>(3)?,
+            "source_id": row.try_get::<Option<String>, _>(4)?,
+            "subregion_code": row.try_get::<Option<String>, _>(5)?,
+            "latitude": row.try_get::<Option<f64>, _>(6)?,
+            "longitude": row.try_get::<Option<f64>, _>(7)?,
+            "valid_from": row.try_get::<Option<i64>, _>(8)?,
+            "valid_until": row.try_get::<Option<i64>, _>(9)?,
+            "provenance": row.try_get::<Option<String>, _>(10)?,
+        }));
+    }
+
+    let keyphrase_count: usize = regions.values().map(|keyphrases| keyphrases.len()).sum();
+    write(export_path, to_string_pretty(&regions)?)?;
+    tracing::info!(
+        "Exported {} keyphrases across {} regions to {}.",
+        keyphrase_count,
+        regions.len(),
+        export_path
+    );
+
+    Ok(())
+}
+
+// Exports the keyphrases the classifier actually matches, with acronyms and subregions: stored and manual keyphrases
+// after the former figure cutoff, the territory policy, blacklisting, ambiguity removal, and short-string and enterprise
+// variants. It is meant for review, so import rejects it.
+pub async fn export_effective_keyphrases(docker_volume: &str, export_path: &str) -> Result<()> {
+    let db_path = format!("{}/region_db.sqlite", docker_volume);
+    let db_path = Path::new(&db_path);
+    let pool = get_db_pool(db_path).await?;
+    create_keyphrase_db(&pool).await?;
+
+    let mut regions: BTreeMap<&str, Vec<(&str, &str)>> = BTreeMap::new();
+    for (keyphrases, region) in KEYPHRASE_REGION_MAP
+        .iter()
+        .chain(KEYPHRASE_SUBREGION_MAP.iter())
+    {
+        regions.entry(region).or_default().extend(
+            keyphrases
+                .iter()
+                .map(|(phrase, category)| (*category, *phrase)),
+        );
+    }
+    for (acronyms, region) in KEYPHRASE_ACRONYM_MAP.iter() {
+        regions
+            .entry(region)
+            .or_default()
+            .extend(acronyms.iter().map(|acronym| ("acronym", *acronym)));
+    }
+
+    let keyphrase_count: usize = regions.values().map(|keyphrases| keyphrases.len()).sum();
+    let regions: BTreeMap<&str, Vec<Value>> = regions
+        .into_iter()
+        .map(|(region, mut keyphrases)| {
+            keyphrases.sort_unstable();
+            let keyphrases = keyphrases
+                .into_iter()
+                .map(|(category, phrase)| json!({ "phrase": phrase, "category": category }))
+                .collect();
+            (region, keyphrases)
+        })
+        .collect();
+    write(
+        export_path,
+        to_string_pretty(&json!({ "mode": "effective", "regions": regions }))?,
+    )?;
+    tracing::info!(
+        "Exported {} effective keyphrases across {} regions to {}.",
+        keyphrase_count,
+        regions.len(),
+        export_path
+    );
+
+    Ok(())
+}
+
+// Imports keyphrases from an export. Manual keyphrases are skipped since they live in the source code,
+// and keyphrases without a source are stored as "import" overrides, which refreshes never remove.
+pub async fn import_keyphrases(docker_volume: &str, import_path: &str) -> Result<()> {
+    let db_path = format!("{}/region_db.sqlite", docker_volume);
+    let db_path = Path::new(&db_path);
+    let pool = get_db_pool(db_path).await?;
+    create_keyphrase_db(&pool).await?;
+
+    let json: Value = from_str(&read_to_string(import_path)?)?;
+    if json["mode"].as_str() == Some("effective") {
+        let err = format!(
+            "{import_path} is an effective export, which cannot be imported. Export without \"effective\" instead."
+        );
+        tracing::error!(err);
+        return Err(anyhow!(err));
+    }
+    let Some(regions) = json.as_object() else {
+        let err = format!("Expected an object of region codes in {import_path}");
+        tracing::error!(err);
+        return Err(anyhow!(err));
+    };
+
+    let mut sources: HashMap<String, Vec<Keyphrase>> = HashMap::new();
+    for (region_code, keyphrases) in regions {
+        for keyphrase in keyphrases.as_array().into_iter().flatten() {
+            let Some(phrase) = keyphrase["phrase"].as_str() else {
+                tracing::error!("Skipping keyphrase without a phrase: {:?}", keyphrase);
+                continue;
+            };
+
+            let source = keyphrase["source"].as_str().unwrap_or("import");
+            if source == "manual" {
+                continue;
+            }
+
+            sources
+                .entry(source.to_string())
+                .or_default()
+                .push(Keyphrase {
                     region_code: region_code.to_string(),
-                    keyphrases: head_of_state,
-                },
-            )
-            .await?;
+                    phrase: phrase.to_string(),
+                    category: keyphrase["category"].as_str().unwrap_or("misc").to_string(),
+                    source_id: keyphrase["source_id"]
+                        .as_str()
+                        .unwrap_or_default()
+                        .to_string(),
+                    subregion_code: keyphrase["subregion_code"]
+                        .as_str()
+                        .map(|code| code.to_lowercase()),
+                    coordinates: keyphrase["latitude"]
+                        .as_f64()
+                        .zip(keyphrase["longitude"].as_f64()),
+                    validity: (
+                        keyphrase["valid_from"].as_i64(),
+                        keyphrase["valid_until"].as_i64(),
+                    ),
+                    provenance: keyphrase["provenance"].as_str().map(|p| p.to_string()),
+                });
         }
     }
 
-    let largest_billionaires = get_largest_billionaires_map(&client).await?;
-    for (calculated_code, billionaires) in largest_billionaires {
-        for keyphrase in billionaires {
-            update_region(
-                &pool,
-                Region {
-                    region_code: calculated_code.to_string(),
-                    keyphrases: keyphrase,
-                },
+    let now = get_now()?;
+    for (source, keyphrases) in sources {
+        let keyphrase_count = keyphrases.len();
+        let mut tx = pool.begin().await?;
+        for keyphrase in keyphrases {
+            sqlx::query(
+                "INSERT OR IGNORE INTO keyphrases (region_code, phrase, category, source, source_id, subregion_code, latitude, longitude, valid_from, valid_until, provenance, added_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             )
+            .bind(keyphrase.region_code.to_lowercase())
+            .bind(unidecode(&keyphrase.phrase.to_lowercase()).trim())
+            .bind(&keyphrase.category)
+            .bind(&source)
+            .bind(&keyphrase.source_id)
+            .bind(&keyphrase.subregion_code)
+            .bind(keyphrase.coordinates.map(|(latitude, _)| latitude))
+            .bind(keyphrase.coordinates.map(|(_, longitude)| longitude))
+            .bind(keyphrase.validity.0)
+            .bind(keyphrase.validity.1)
+            .bind(&keyphrase.provenance)
+            .bind(now)
+            .execute(&mut *tx)
             .await?;
         }
-    }
-
-    let largest_private_enterprises = get_private_enterprises_map(&client).await?;
-    for (calculated_code, largest_private_enterprises) in largest_private_enterprises {
-        for keyphrase in largest_private_enterprises {
-            update_region(
-                &pool,
-                Region {
-                    region_code: calculated_code.to_string(),
-                    keyphrases: keyphrase,
-                },
+        if SOURCES.contains(&source.as_str()) {
+            // Imported automated sources count as freshly refreshed, so they are not immediately replaced.
+            sqlx::query(
+                "INSERT INTO source_refreshes (source, refreshed_at) VALUES (?, ?)
+                ON CONFLICT (source) DO UPDATE SET refreshed_at = excluded.refreshed_at",
             )
+            .bind(&source)
+            .bind(now)
+            .execute(&mut *tx)
             .await?;
         }
+        tx.commit().await?;
+        tracing::info!("Imported {keyphrase_count} {source} keyphrases.");
     }
 
     Ok(())
 }
 
-async fn update_region(
-    pool: &sqlx::Pool<sqlx::Sqlite>,
-    region: Region,
-) -> Result<SqliteQueryResult> {
-    let region = Region {
-        region_code: region.region_code.to_lowercase(),
-        keyphrases: region.keyphrases.to_lowercase(),
-    };
-    let row: Option<(String,)> =
-        sqlx::query_as("SELECT keyphrases FROM regions WHERE region_code = $1")
-            .bind(&region.region_code)
-            .fetch_optional(pool)
-            .await?;
+// Region codes whose office holders are loaded from Wikidata.
+fn get_region_codes() -> Vec<&'static str> {
+    COUNTRIES.iter().map(|country| country.alpha2).collect()
+}
 
-    let region_keyphrases = unidecode(&region.keyphrases);
-    match row {
-        Some((existing_keyphrases,)) => {
-            let mut keyphrases = existing_keyphrases.split(',').collect::<Vec<&str>>();
-            keyphrases.extend(region_keyphrases.split(','));
-            let keyphrases: HashSet<&str> = keyphrases.into_par_iter().collect();
-            let keyphrases = keyphrases.into_par_iter().collect::<Vec<&str>>().join(",");
-            Ok(
-                sqlx::query("UPDATE regions SET keyphrases = $1 WHERE region_code = $2")
-                    .bind(keyphrases)
-                    .bind(region.region_code)
-                    .execute(pool)
-                    .await?,
-            )
-        }
-        None => Ok(
-            sqlx::query("INSERT INTO regions (region_code, keyphrases) VALUES ($1, $2)")
-                .bind(region.region_code)
-                .bind(region_keyphrases)
-                .execute(pool)
-                .await?,
-        ),
+fn get_now() -> Result<i64> {
+    Ok(SystemTime::now()
+        .duration_since(UNIX_EPOCH)?
+        .as_secs()
+        .try_into()?)
+}
+
+// Writes how the ISO codes derived from Wikidata disagree with the region codes.
+pub async fn verify_iso_codes(docker_volume: &str, report_path: &str) -> Result<()> {
+    report_iso_codes(docker_volume, &get_region_codes(), report_path).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn builds_keyphrase_db_offline() {
+        let fixture = |path: &str| {
+            Some(format!(
+                "{}/tests/fixtures/{path}",
+                env!("CARGO_MANIFEST_DIR")
+            ))
+        };
+        let paths = SourcePaths {
+            geonames: fixture("keyphrases/geonames.txt"),
+            alternate_names: fixture("keyphrases/alternate_names.txt"),
+            wikidata: fixture("keyphrases/wikidata.json"),
+            positions: fixture("keyphrases/positions.json"),
+            forbes: fixture("keyphrases/forbes.json"),
+            wikipedia: fixture("keyphrases/companies.wiki"),
+            ofac_sdn: fixture("sanctions/sdn.csv"),
+            ofac_add: fixture("sanctions/add.csv"),
+            un_sanctions: fixture("sanctions/un.xml"),
+            eu_sanctions: fixture("sanctions/eu.xml"),
+        };
+        let dir = tempdir().unwrap();
+        let docker_volume = dir.path().to_str().unwrap();
+
+        refresh_sources(docker_volume, &[], &paths).await.unwrap();
+
+        let pool = get_db_pool(Path::new(&format!("{docker_volume}/region_db.sqlite")))
+            .await
+            .unwrap();
+        let refreshed: Vec<String> =
+            sqlx::query_scalar("SELECT source FROM source_refreshes ORDER BY source")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            refreshed,
+            vec![
+                "forbes",
+                "geonames",
+                "positions",
+                "sanctions",
+                "wikidata",
+                "wikipedia"
+            ]
+        );
+
+        let keyphrases: Vec<(String, String, String)> =
+            sqlx::query_as("SELECT source, region_code, phrase FROM keyphrases ORDER BY source, region_code, phrase")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        let keyphrases: Vec<(&str, &str, &str)> = keyphrases
+            .iter()
+            .filter(|(source, _, _)| source != "sanctions")
+            .map(|(source, region_code, phrase)| {
+                (source.as_str(), region_code.as_str(), phrase.as_str())
+            })
+            .collect();
+        assert_eq!(
+            keyphrases,
+            vec![
+                ("forbes", "au", "gina rinehart"),
+                ("forbes", "fr", "bernard arnault"),
+                ("geonames", "de", "berlin"),
+                ("geonames", "fr", "paris"),
+                ("geonames", "ua", "kharkiv"),
+                ("geonames", "ua", "kharkov"),
+                ("geonames", "ua", "kiev"),
+                ("geonames", "ua", "kyiv"),
+                ("positions", "ua", "andrii sybiha"),
+                ("wikidata", "fr", "emmanuel macron"),
+                ("wikipedia", "de", "schwarz gruppe"),
+                ("wikipedia", "us", "cargill"),
+                ("wikipedia", "us", "koch"),
+            ]
+        );
+        let sanctioned: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM keyphrases WHERE source = 'sanctions'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert!(sanctioned > 0);
     }
 }
@@ -1,10 +1,18 @@
 use crate::prelude::*;
+use crate::scrape::{
+    iso3166::COUNTRIES,
+    region::get_bloc_members,
+    util::{Classification, RegionBasis},
+};
 use sqlx::SqlitePool;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::{
+    collections::BTreeMap,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 pub async fn update_media_db(
     pool: &SqlitePool,
-    media: Vec<(String, String, String, Vec<String>)>,
+    media: Vec<(String, String, String, Classification)>,
 ) -> Result<()> {
     let now: i32 = SystemTime::now()
         .duration_since(UNIX_EPOCH)?
@@ -15,27 +23,131 @@ pub async fn update_media_db(
         .execute(pool)
         .await?;
 
-    for (url, title, body, regions) in &media {
-        if url.is_empty() || title.is_empty() || body.is_empty() || regions.is_empty() {
+    // Evidence, fallback, and unknown counts of each source.
+    let mut basis_counts: BTreeMap<&str, [usize; 3]> = BTreeMap::new();
+    for (url, title, body, classification) in &media {
+        let regions = &classification.regions;
+        if url.is_empty() || title.is_empty() || body.is_empty() {
             continue;
         }
 
-        sqlx::query("INSERT OR IGNORE INTO urls (url, timestamp, title, body) VALUES (?, ?, ?, ?)")
+        let counts = basis_counts.entry(classification.source).or_default();
+        match classification.basis {
+            RegionBasis::Evidence => counts[0] += 1,
+            RegionBasis::Fallback => counts[1] += 1,
+            RegionBasis::Unknown => counts[2] += 1,
+        }
+        sqlx::query(
+            "INSERT OR IGNORE INTO urls (url, timestamp, title, body, region_basis, primary_region)
+            VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(url)
+        .bind(now)
+        .bind(title)
+        .bind(body)
+        .bind(classification.basis.as_str())
+        .bind(&classification.primary_region)
+        .execute(pool)
+        .await?;
+
+        for region in regions {
+            // Subregion codes such as "ua-14", made of GeoNames admin1 codes, are stored alongside their country code.
+            let parent_code = region.split_once('-').map(|(parent_code, _)| parent_code);
+            sqlx::query(
+                "INSERT OR IGNORE INTO url_regions (url, region_code, parent_code) VALUES (?, ?, ?)",
+            )
             .bind(url)
-            .bind(now)
-            .bind(title)
-            .bind(body)
+            .bind(region)
+            .bind(parent_code)
             .execute(pool)
             .await?;
+        }
 
-        for region in regions {
-            sqlx::query("INSERT OR IGNORE INTO url_regions (url, region_code) VALUES (?, ?)")
+        for place in &classification.places {
+            sqlx::query(
+                "INSERT OR IGNORE INTO url_places (url, geoname_id, name, region_code, latitude, longitude)
+                VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .bind(url)
+            .bind(&place.geoname_id)
+            .bind(place.name)
+            .bind(place.region)
+            .bind(place.latitude)
+            .bind(place.longitude)
+            .execute(pool)
+            .await?;
+        }
+
+        for entity in &classification.entities {
+            sqlx::query(
+                "INSERT OR REPLACE INTO entities (entity_id, name, entity_type, region_code)
+                VALUES (?, ?, ?, ?)",
+            )
+            .bind(&entity.entity_id)
+            .bind(entity.name)
+            .bind(entity.entity_type)
+            .bind(entity.region)
+            .execute(pool)
+            .await?;
+            sqlx::query("INSERT OR IGNORE INTO url_entities (url, entity_id) VALUES (?, ?)")
                 .bind(url)
-                .bind(region)
+                .bind(&entity.entity_id)
                 .execute(pool)
                 .await?;
         }
     }
 
+    for (source, [evidence, fallback, unknown]) in basis_counts {
+        tracing::info!(
+            "Classified {source} media: {evidence} by evidence, {fallback} by fallback, {unknown} unknown."
+        );
+    }
+
+    Ok(())
+}
+
+// Mirrors the bloc memberships in the source code, so that bloc codes in url_regions can be joined to countries.
+pub async fn update_bloc_members(pool: &SqlitePool) -> Result<()> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM bloc_members")
+        .execute(&mut *tx)
+        .await?;
+    for (bloc, members) in get_bloc_members() {
+        for member in members {
+            sqlx::query("INSERT INTO bloc_members (bloc_code, region_code) VALUES (?, ?)")
+                .bind(bloc)
+                .bind(member)
+                .execute(&mut *tx)
+                .await?;
+        }
+    }
+    tx.commit().await?;
+
+    Ok(())
+}
+
+// Mirrors the ISO 3166 registry, so that region codes can be joined to country names, continents, and subregions.
+pub async fn update_countries(pool: &SqlitePool) -> Result<()> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM countries")
+        .execute(&mut *tx)
+        .await?;
+    for country in COUNTRIES.iter() {
+        sqlx::query(
+            "INSERT INTO countries (region_code, alpha3, numeric_code, name, official_name, continent, subregion)
+            VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(country.alpha2)
+        .bind(country.alpha3)
+        .bind(country.numeric)
+        .bind(country.short_name)
+        .bind(country.official_name)
+        .bind(country.continent)
+        .bind(country.subregion)
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+
     Ok(())
 }
@@ -0,0 +1,366 @@
+use super::keyphrase::get_subregion_code;
+use crate::prelude::*;
+use anyhow::anyhow;
+use serde_json::{from_str, Value};
+use std::{collections::HashMap, fs::read_to_string};
+
+struct FeatureClassRule {
+    min_population: u32,
+    always_included_codes: Vec<String>,
+}
+
+struct NameExclusion {
+    country: String,
+    contains: String,
+}
+
+// Decides which GeoNames entries become keyphrases. Per-country rules replace the default feature class rules.
+pub struct GeonamesPolicy {
+    feature_classes: HashMap<String, FeatureClassRule>,
+    country_feature_classes: HashMap<String, HashMap<String, FeatureClassRule>>,
+    excluded_code_chars: Vec<char>,
+    excluded_codes: Vec<String>,
+    name_exclusions: Vec<NameExclusion>,
+}
+
+impl Default for GeonamesPolicy {
+    fn default() -> Self {
+        GeonamesPolicy {
+            feature_classes: HashMap::from([
+                (
+                    "A".to_string(),
+                    FeatureClassRule {
+                        min_population: 490000,
+                        always_included_codes: Vec::new(),
+                    },
+                ),
+                (
+                    "P".to_string(),
+                    FeatureClassRule {
+                        min_population: 290000,
+                        always_included_codes: vec!["PPLC".to_string()],
+                    },
+                ),
+            ]),
+            country_feature_classes: HashMap::new(),
+            excluded_code_chars: vec!['H'],
+            excluded_codes: Vec::new(),
+            name_exclusions: vec![NameExclusion {
+                country: "MZ".to_string(),
+                contains: "aza".to_string(),
+            }], // "Gaza" is a better keyphrase for Palestine than Mozambique.
+        }
+    }
+}
+
+impl GeonamesPolicy {
+    pub fn includes(
+        &self,
+        country: &str,
+        feature_class: &str,
+        feature_code: &str,
+        population: u32,
+        ascii_name: &str,
+    ) -> bool {
+        if feature_code
+            .chars()
+            .any(|c| self.excluded_code_chars.contains(&c))
+            || self.excluded_codes.iter().any(|code| code == feature_code)
+        {
+            return false;
+        }
+
+        if self.name_exclusions.iter().any(|exclusion| {
+            exclusion.country == country && ascii_name.contains(&exclusion.contains)
+        }) {
+            return false;
+        }
+
+        let feature_classes = self
+            .country_feature_classes
+            .get(country)
+            .unwrap_or(&self.feature_classes);
+        match feature_classes.get(feature_class) {
+            Some(rule) => {
+                population >= rule.min_population
+                    || rule
+                        .always_included_codes
+                        .iter()
+                        .any(|code| code == feature_code)
+            }
+            None => false,
+        }
+    }
+}
+
+pub fn load_geonames_policy(policy_path: Option<&str>) -> Result<GeonamesPolicy> {
+    let Some(policy_path) = policy_path else {
+        return Ok(GeonamesPolicy::default());
+    };
+
+    let json: Value = from_str(&read_to_string(policy_path)?)?;
+    let mut policy = GeonamesPolicy::default();
+    if !json["feature_classes"].is_null() {
+        policy.feature_classes = parse_feature_classes(&json["feature_classes"])?;
+    }
+    if let Some(countries) = json["countries"].as_object() {
+        for (country, rules) in countries {
+            policy.country_feature_classes.insert(
+                country.to_uppercase(),
+                parse_feature_classes(&rules["feature_classes"])?,
+            );
+        }
+    }
+    if let Some(chars) = json["excluded_feature_code_chars"].as_array() {
+        policy.excluded_code_chars = chars
+            .iter()
+            .filter_map(|c| c.as_str().and_then(|c| c.chars().next()))
+            .collect();
+    }
+    if let Some(codes) = json["excluded_feature_codes"].as_array() {
+        policy.excluded_codes = get_strings(codes);
+    }
+    if let Some(exclusions) = json["name_exclusions"].as_array() {
+        policy.name_exclusions = exclusions
+            .iter()
+            .filter_map(|exclusion| {
+                Some(NameExclusion {
+                    country: exclusion["country"].as_str()?.to_uppercase(),
+                    contains: exclusion["contains"].as_str()?.to_string(),
+                })
+            })
+            .collect();
+    }
+
+    Ok(policy)
+}
+
+fn parse_feature_classes(json: &Value) -> Result<HashMap<String, FeatureClassRule>> {
+    let Some(feature_classes) = json.as_object() else {
+        let err = format!("Expected an object of feature classes, got {json}");
+        tracing::error!(err);
+        return Err(anyhow!(err));
+    };
+
+    Ok(feature_classes
+        .iter()
+        .map(|(feature_class, rule)| {
+            let min_population = rule["min_population"]
+                .as_u64()
+                .and_then(|min_population| min_population.try_into().ok())
+                .unwrap_or(u32::MAX);
+            let always_included_codes = rule["always_included_codes"]
+                .as_array()
+                .map(|codes| get_strings(codes))
+                .unwrap_or_default();
+            (
+                feature_class.to_uppercase(),
+                FeatureClassRule {
+                    min_population,
+                    always_included_codes,
+                },
+            )
+        })
+        .collect())
+}
+
+fn get_strings(values: &[Value]) -> Vec<String> {
+    values
+        .iter()
+        .filter_map(|value| value.as_str().map(|value| value.to_string()))
+        .collect()
+}
+
+// How places in a disputed or partially recognized territory are coded: with the territory's own code, with both it and
+// its parent's, or with the parent's alone.
+#[derive(Clone, Copy, PartialEq)]
+enum TerritoryMode {
+    Separate,
+    Both,
+    Parent,
+}
+
+impl TerritoryMode {
+    fn parse(mode: &str) -> Result<TerritoryMode> {
+        match mode {
+            "separate" => Ok(TerritoryMode::Separate),
+            "both" => Ok(TerritoryMode::Both),
+            "parent" => Ok(TerritoryMode::Parent),
+            _ => {
+                let err = format!("Unknown territory mode: {mode}");
+                tracing::error!(err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            TerritoryMode::Separate => "separate",
+            TerritoryMode::Both => "both",
+            TerritoryMode::Parent => "parent",
+        }
+    }
+}
+
+struct Territory {
+    code: String, // A country code such as "ps", or a GeoNames admin1 subregion code such as "ua-11".
+    parent: String,
+    mode: TerritoryMode,
+}
+
+pub struct TerritoryPolicy {
+    territories: Vec<Territory>,
+}
+
+impl TerritoryPolicy {
+    fn new(mode: TerritoryMode) -> Self {
+        // Crimea and Sevastopol are the GeoNames admin1 divisions 11 and 20 of Ukraine. Northern Cyprus has no code of its
+        // own, and Kyrenia (02) is the only district wholly within it, as Nicosia and Famagusta straddle the Green Line.
+        let subregions = [("cy", "02", "tr"), ("ua", "11", "ru"), ("ua", "20", "ru")];
+        let countries = [("eh", "ma"), ("ps", "il"), ("tw", "cn"), ("xk", "rs")];
+        let territories = subregions
+            .iter()
+            .filter_map(|(country, admin1_code, parent)| {
+                Some((get_subregion_code(country, admin1_code)?, *parent))
+            })
+            .chain(
+                countries
+                    .iter()
+                    .map(|(code, parent)| (code.to_string(), *parent)),
+            );
+        TerritoryPolicy {
+            territories: territories
+                .map(|(code, parent)| Territory {
+                    code,
+                    parent: parent.to_string(),
+                    mode,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn is_territory(&self, code: &str) -> bool {
+        self.territories
+            .iter()
+            .any(|territory| territory.code == code)
+    }
+
+    // Region codes a keyphrase counts toward. Its subregion is checked first, so that places in a disputed subregion,
+    // such as Crimea, can count toward another country than the one GeoNames lists.
+    pub fn get_keyphrase_codes<'a>(
+        &'a self,
+        region_code: &'a str,
+        subregion_code: Option<&'a str>,
+    ) -> Vec<&'a str> {
+        let code = subregion_code
+            .filter(|subregion_code| self.is_territory(subregion_code))
+            .unwrap_or(region_code);
+        self.get_codes(code)
+            .into_iter()
+            .map(|territory_code| match territory_code == code {
+                true => region_code,
+                false => territory_code,
+            })
+            .collect()
+    }
+
+    // Codes a region code stands for, the territory's own first unless it gives way to its parent's.
+    pub fn get_codes<'a>(&'a self, code: &'a str) -> Vec<&'a str> {
+        match self
+            .territories
+            .iter()
+            .find(|territory| territory.code == code)
+        {
+            Some(territory) => match territory.mode {
+                TerritoryMode::Separate => vec![code],
+                TerritoryMode::Both => vec![code, &territory.parent],
+                TerritoryMode::Parent => vec![&territory.parent],
+            },
+            None => vec![code],
+        }
+    }
+
+    // Describes the policy for the keyphrase index hash, since the compiled maps depend on it.
+    pub fn get_version(&self) -> String {
+        self.territories
+            .iter()
+            .map(|territory| {
+                format!(
+                    "{}:{}:{}",
+                    territory.code,
+                    territory.parent,
+                    territory.mode.as_str()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+impl Default for TerritoryPolicy {
+    fn default() -> Self {
+        TerritoryPolicy::new(TerritoryMode::Separate)
+    }
+}
+
+// Listed territories replace the default ones, and take default_mode unless they give their own.
+pub fn load_territory_policy(policy_path: Option<&str>) -> Result<TerritoryPolicy> {
+    let Some(policy_path) = policy_path else {
+        return Ok(TerritoryPolicy::default());
+    };
+
+    let json: Value = from_str(&read_to_string(policy_path)?)?;
+    let default_mode = match json["default_mode"].as_str() {
+        Some(mode) => TerritoryMode::parse(mode)?,
+        None => TerritoryMode::Separate,
+    };
+    let mut policy = TerritoryPolicy::new(default_mode);
+    if let Some(territories) = json["territories"].as_array() {
+        policy.territories.clear();
+        for territory in territories {
+            let (Some(code), Some(parent)) =
+                (territory["code"].as_str(), territory["parent"].as_str())
+            else {
+                let err = format!("Territory lacks a code or parent: {territory}");
+                tracing::error!(err);
+                return Err(anyhow!(err));
+            };
+
+            policy.territories.push(Territory {
+                code: code.to_lowercase(),
+                parent: parent.to_lowercase(),
+                mode: match territory["mode"].as_str() {
+                    Some(mode) => TerritoryMode::parse(mode)?,
+                    None => default_mode,
+                },
+            });
+        }
+    }
+
+    Ok(policy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_crimean_places_by_mode() {
+        // Simferopol's GeoNames row lists Ukraine and admin1 code 11.
+        let crimea = get_subregion_code("UA", "11").unwrap();
+        let kyiv = get_subregion_code("UA", "12").unwrap();
+        let get_codes = |mode, subregion_code: &str| -> Vec<String> {
+            TerritoryPolicy::new(mode)
+                .get_keyphrase_codes("ua", Some(subregion_code))
+                .into_iter()
+                .map(|code| code.to_string())
+                .collect()
+        };
+
+        assert_eq!(get_codes(TerritoryMode::Parent, &crimea), vec!["ru"]);
+        assert_eq!(get_codes(TerritoryMode::Both, &crimea), vec!["ua", "ru"]);
+        assert_eq!(get_codes(TerritoryMode::Separate, &crimea), vec!["ua"]);
+        assert_eq!(get_codes(TerritoryMode::Parent, &kyiv), vec!["ua"]);
+    }
+}
@@ -0,0 +1,94 @@
+use super::media::update_countries;
+use super::util::{create_media_db, get_db_pool};
+use crate::prelude::*;
+use serde_json::{json, to_string_pretty};
+use sqlx::{Row, SqlitePool};
+use std::{
+    fs::write,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+// Media scraped between from and until (UNIX seconds, until excluded) per continent. A piece of media counts once per
+// continent, however many of its countries are there.
+pub async fn count_media_by_continent(
+    pool: &SqlitePool,
+    from: i64,
+    until: i64,
+) -> Result<Vec<(String, i64)>> {
+    let rows = sqlx::query(
+        "SELECT region_hierarchy.continent, COUNT(DISTINCT urls.url) FROM region_hierarchy
+        JOIN urls ON urls.url = region_hierarchy.url
+        WHERE urls.timestamp >= ? AND urls.timestamp < ?
+        GROUP BY region_hierarchy.continent ORDER BY 2 DESC, 1",
+    )
+    .bind(from)
+    .bind(until)
+    .fetch_all(pool)
+    .await?;
+
+    rows.iter()
+        .map(|row| Ok((row.try_get(0)?, row.try_get(1)?)))
+        .collect()
+}
+
+// Media scraped between from and until per UN M49 subregion, as (continent, subregion, count).
+pub async fn count_media_by_subregion(
+    pool: &SqlitePool,
+    from: i64,
+    until: i64,
+) -> Result<Vec<(String, String, i64)>> {
+    let rows = sqlx::query(
+        "SELECT region_hierarchy.continent, region_hierarchy.subregion, COUNT(DISTINCT urls.url)
+        FROM region_hierarchy
+        JOIN urls ON urls.url = region_hierarchy.url
+        WHERE urls.timestamp >= ? AND urls.timestamp < ? AND region_hierarchy.subregion != ''
+        GROUP BY region_hierarchy.continent, region_hierarchy.subregion ORDER BY 3 DESC, 1, 2",
+    )
+    .bind(from)
+    .bind(until)
+    .fetch_all(pool)
+    .await?;
+
+    rows.iter()
+        .map(|row| Ok((row.try_get(0)?, row.try_get(1)?, row.try_get(2)?)))
+        .collect()
+}
+
+// Writes the continent and subregion counts of the media scraped in the last days to a JSON file.
+pub async fn report_region_rollups(
+    docker_volume: &str,
+    report_path: &str,
+    days: i64,
+) -> Result<()> {
+    let db_path = format!("{}/media_db.sqlite", docker_volume);
+    let db_path = Path::new(&db_path);
+    let pool = get_db_pool(db_path).await?;
+    create_media_db(&pool).await?;
+    update_countries(&pool).await?;
+
+    let until = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64 + 1;
+    let from = until - days * 86400;
+    let continents: Vec<_> = count_media_by_continent(&pool, from, until)
+        .await?
+        .into_iter()
+        .map(|(continent, count)| json!({ "continent": continent, "count": count }))
+        .collect();
+    let subregions: Vec<_> = count_media_by_subregion(&pool, from, until)
+        .await?
+        .into_iter()
+        .map(|(continent, subregion, count)| {
+            json!({ "continent": continent, "subregion": subregion, "count": count })
+        })
+        .collect();
+    let report = json!({
+        "from": from,
+        "until": until,
+        "continents": continents,
+        "subregions": subregions,
+    });
+    write(report_path, to_string_pretty(&report)?)?;
+    tracing::info!("Wrote region rollups of the last {days} days to {report_path}.");
+
+    Ok(())
+}
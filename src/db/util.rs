@@ -20,19 +20,111 @@ pub async fn create_media_db(pool: &SqlitePool) -> Result<()> {
             url TEXT PRIMARY KEY,
             timestamp INTEGER,
             title TEXT,
-            body TEXT
+            body TEXT,
+            region_basis TEXT,
+            primary_region TEXT
         )",
     )
     .await?;
+    add_column_if_missing(pool, "urls", "region_basis", "TEXT").await?;
+    add_column_if_missing(pool, "urls", "primary_region", "TEXT").await?;
     pool.execute(
         "CREATE TABLE IF NOT EXISTS url_regions (
             url TEXT,
             region_code TEXT,
+            parent_code TEXT,
             PRIMARY KEY (url, region_code),
             FOREIGN KEY (url) REFERENCES urls (url)
         )",
     )
     .await?;
+    add_column_if_missing(pool, "url_regions", "parent_code", "TEXT").await?;
+    pool.execute(
+        "CREATE TABLE IF NOT EXISTS bloc_members (
+            bloc_code TEXT,
+            region_code TEXT,
+            PRIMARY KEY (bloc_code, region_code)
+        )",
+    )
+    .await?;
+    pool.execute(
+        "CREATE TABLE IF NOT EXISTS countries (
+            region_code TEXT PRIMARY KEY,
+            alpha3 TEXT,
+            numeric_code INTEGER,
+            name TEXT,
+            official_name TEXT,
+            continent TEXT,
+            subregion TEXT
+        )",
+    )
+    .await?;
+    // Country, UN M49 subregion, and continent of each stored region code. Subregion codes roll up through their
+    // parent country, and bloc codes have no row.
+    pool.execute(
+        "CREATE VIEW IF NOT EXISTS region_hierarchy AS
+        SELECT url_regions.url, url_regions.region_code, countries.region_code AS country_code,
+            countries.subregion, countries.continent
+        FROM url_regions
+        JOIN countries ON countries.region_code = COALESCE(url_regions.parent_code, url_regions.region_code)",
+    )
+    .await?;
+    pool.execute(
+        "CREATE TABLE IF NOT EXISTS url_places (
+            url TEXT,
+            geoname_id TEXT,
+            name TEXT,
+            region_code TEXT,
+            latitude REAL,
+            longitude REAL,
+            PRIMARY KEY (url, geoname_id),
+            FOREIGN KEY (url) REFERENCES urls (url)
+        )",
+    )
+    .await?;
+    pool.execute(
+        "CREATE TABLE IF NOT EXISTS entities (
+            entity_id TEXT PRIMARY KEY,
+            name TEXT,
+            entity_type TEXT,
+            region_code TEXT
+        )",
+    )
+    .await?;
+    pool.execute(
+        "CREATE TABLE IF NOT EXISTS url_entities (
+            url TEXT,
+            entity_id TEXT,
+            PRIMARY KEY (url, entity_id),
+            FOREIGN KEY (url) REFERENCES urls (url),
+            FOREIGN KEY (entity_id) REFERENCES entities (entity_id)
+        )",
+    )
+    .await?;
+
+    Ok(())
+}
+
+// Brings tables created by older versions up to date, since CREATE TABLE IF NOT EXISTS leaves them as they were.
+pub async fn add_column_if_missing(
+    pool: &SqlitePool,
+    table: &str,
+    column: &str,
+    column_type: &str,
+) -> Result<()> {
+    let columns = sqlx::query(&format!("PRAGMA table_info({table})"))
+        .fetch_all(pool)
+        .await?;
+    if columns
+        .iter()
+        .any(|row| row.get::<String, _>("name") == column)
+    {
+        return Ok(());
+    }
+
+    pool.execute(format!("ALTER TABLE {table} ADD COLUMN {column} {column_type}").as_str())
+        .await?;
+    tracing::info!("Added column {column} to {table}.");
 
     Ok(())
 }
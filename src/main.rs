@@ -1,6 +1,9 @@
 mod db {
+    pub mod index;
     pub mod keyphrase;
     pub mod media;
+    pub mod policy;
+    pub mod rollup;
     pub mod util;
 }
 mod scrape {
@@ -24,12 +27,15 @@ mod scrape {
             pub mod truthout;
             pub mod ur;
         }
+        pub mod billionaires;
         pub mod forbes400;
+        pub mod sanctions;
         pub mod substack;
         pub mod wikidata;
         pub mod wikipedia;
         pub mod youtube;
     }
+    pub mod iso3166;
     pub mod region;
     pub mod util;
 }
@@ -41,7 +47,12 @@ mod service {
 }
 mod prelude;
 use crate::prelude::*;
-use db::keyphrase::gen_keyphrase_db;
+use db::keyphrase::{
+    export_effective_keyphrases, export_keyphrases, gen_keyphrase_db, import_keyphrases,
+    refresh_keyphrase_db, report_geonames_policy, verify_iso_codes,
+};
+use db::rollup::report_region_rollups;
+use std::env::args;
 //use scrape::region;
 use service::{
     scrape_service::run_scrapers, var_service::get_docker_volume, venv_service::create_venv,
@@ -52,6 +63,49 @@ use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt};
 async fn main() -> Result<()> {
     tracing_subscriber::registry().with(fmt::layer()).init();
     let docker_volume = get_docker_volume().await?;
+    let args: Vec<String> = args().skip(1).collect();
+    if let Some(command) = args.first() {
+        match command.as_str() {
+            "refresh" => refresh_keyphrase_db(&docker_volume, &args[1..]).await?,
+            "export" | "import" => match args.get(1) {
+                Some(path) if command == "export" => match args.get(2).map(|mode| mode.as_str()) {
+                    Some("effective") => export_effective_keyphrases(&docker_volume, path).await?,
+                    Some(mode) => tracing::error!("Unknown export mode: {mode}"),
+                    None => export_keyphrases(&docker_volume, path).await?,
+                },
+                Some(path) => import_keyphrases(&docker_volume, path).await?,
+                None => tracing::error!("Missing file path for {command}"),
+            },
+            "policy-report" => match args.get(1) {
+                Some(path) => report_geonames_policy(&docker_volume, path).await?,
+                None => tracing::error!("Missing policy file path for {command}"),
+            },
+            "verify-codes" => {
+                let report_path = match args.get(1) {
+                    Some(path) => path.to_string(),
+                    None => format!("{}/wikidata_code_report.json", docker_volume),
+                };
+                verify_iso_codes(&docker_volume, &report_path).await?
+            }
+            "rollup" => match args.get(1) {
+                Some(path) => {
+                    let days = match args.get(2).map(|days| days.parse::<i64>()) {
+                        Some(Ok(days)) => days,
+                        Some(Err(_)) => {
+                            tracing::error!("Invalid number of days for {command}");
+                            return Ok(());
+                        }
+                        None => 7,
+                    };
+                    report_region_rollups(&docker_volume, path, days).await?
+                }
+                None => tracing::error!("Missing report file path for {command}"),
+            },
+            _ => tracing::error!("Unknown command: {command}"),
+        }
+        return Ok(());
+    }
+
     create_venv(&docker_volume).await?;
     gen_keyphrase_db(&docker_volume).await?;
     //region::show_region_map().await?;
@@ -0,0 +1,2625 @@
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use unidecode::unidecode;
+
+// An ISO 3166-1 country. Codes are lowercase like region codes, continents follow the UN M49 regions with the
+// Americas split into North and South America, and Kosovo has no numeric code.
+pub struct Country {
+    pub alpha2: &'static str,
+    pub alpha3: &'static str,
+    pub numeric: Option<u16>,
+    pub short_name: &'static str,
+    pub official_name: &'static str,
+    pub continent: &'static str,
+    pub subregion: &'static str, // UN M49 subregion, empty for Antarctica.
+    pub aliases: &'static [&'static str],
+}
+
+static CODE_MAP: Lazy<HashMap<String, &'static Country>> = Lazy::new(|| {
+    let mut code_map = HashMap::new();
+    for country in COUNTRIES.iter() {
+        code_map.insert(country.alpha2.to_string(), country);
+        code_map.insert(country.alpha3.to_string(), country);
+        if let Some(numeric) = country.numeric {
+            code_map.insert(format!("{:03}", numeric), country);
+        }
+    }
+
+    code_map
+});
+
+static NAME_MAP: Lazy<HashMap<String, &'static Country>> = Lazy::new(|| {
+    let mut name_map = HashMap::new();
+    for country in COUNTRIES.iter() {
+        for name in [country.short_name, country.official_name]
+            .iter()
+            .chain(country.aliases)
+        {
+            name_map.insert(normalize_name(name), country);
+        }
+    }
+
+    name_map
+});
+
+// Alpha-2, alpha-3, or numeric code in any case.
+pub fn get_country(code: &str) -> Option<&'static Country> {
+    let code = code.trim().to_lowercase();
+    match code.parse::<u16>() {
+        Ok(numeric) => CODE_MAP.get(&format!("{:03}", numeric)).copied(),
+        Err(_) => CODE_MAP.get(&code).copied(),
+    }
+}
+
+// Short, official, ISO, or common name, ignoring case, diacritics, and punctuation, so that "Türkiye", "Czechia",
+// "Cote d'Ivoire", and "Korea, Republic of" are all found.
+pub fn get_country_from_name(name: &str) -> Option<&'static Country> {
+    let name = normalize_name(name);
+    if let Some(country) = NAME_MAP.get(&name) {
+        return Some(country);
+    }
+
+    // Lists such as OFAC's put the distinguishing part last, as in "Korea, North".
+    if let Some((first, second)) = name.split_once(", ") {
+        if let Some(country) = NAME_MAP.get(&format!("{second} {first}")) {
+            return Some(country);
+        }
+    }
+
+    // Forbes adds former names, as in "Eswatini (Swaziland)".
+    name.split_once(" (")
+        .and_then(|(name, _)| NAME_MAP.get(name.trim()).copied())
+}
+
+pub fn get_code_from_name(name: &str) -> Option<&'static str> {
+    get_country_from_name(name).map(|country| country.alpha2)
+}
+
+pub fn get_name_from_code(code: &str) -> Option<&'static str> {
+    get_country(code).map(|country| country.short_name)
+}
+
+fn normalize_name(name: &str) -> String {
+    let name = unidecode(name)
+        .to_lowercase()
+        .replace('&', " and ")
+        .replace(['.', '\'', '`'], "")
+        .replace('-', " ");
+    let name = name.split_whitespace().collect::<Vec<&str>>().join(" ");
+    match name.strip_prefix("the ") {
+        Some(name) => name.to_string(),
+        None => name,
+    }
+}
+
+pub static COUNTRIES: [Country; 250] = [
+    Country {
+        alpha2: "ad",
+        alpha3: "and",
+        numeric: Some(20),
+        short_name: "Andorra",
+        official_name: "Principality of Andorra",
+        continent: "Europe",
+        subregion: "Southern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ae",
+        alpha3: "are",
+        numeric: Some(784),
+        short_name: "United Arab Emirates",
+        official_name: "United Arab Emirates",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &["UAE"],
+    },
+    Country {
+        alpha2: "af",
+        alpha3: "afg",
+        numeric: Some(4),
+        short_name: "Afghanistan",
+        official_name: "Islamic Republic of Afghanistan",
+        continent: "Asia",
+        subregion: "Southern Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ag",
+        alpha3: "atg",
+        numeric: Some(28),
+        short_name: "Antigua and Barbuda",
+        official_name: "Antigua and Barbuda",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ai",
+        alpha3: "aia",
+        numeric: Some(660),
+        short_name: "Anguilla",
+        official_name: "Anguilla",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "al",
+        alpha3: "alb",
+        numeric: Some(8),
+        short_name: "Albania",
+        official_name: "Republic of Albania",
+        continent: "Europe",
+        subregion: "Southern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "am",
+        alpha3: "arm",
+        numeric: Some(51),
+        short_name: "Armenia",
+        official_name: "Republic of Armenia",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ao",
+        alpha3: "ago",
+        numeric: Some(24),
+        short_name: "Angola",
+        official_name: "Republic of Angola",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "aq",
+        alpha3: "ata",
+        numeric: Some(10),
+        short_name: "Antarctica",
+        official_name: "Antarctica",
+        continent: "Antarctica",
+        subregion: "",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ar",
+        alpha3: "arg",
+        numeric: Some(32),
+        short_name: "Argentina",
+        official_name: "Argentine Republic",
+        continent: "South America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "as",
+        alpha3: "asm",
+        numeric: Some(16),
+        short_name: "American Samoa",
+        official_name: "American Samoa",
+        continent: "Oceania",
+        subregion: "Polynesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "at",
+        alpha3: "aut",
+        numeric: Some(40),
+        short_name: "Austria",
+        official_name: "Republic of Austria",
+        continent: "Europe",
+        subregion: "Western Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "au",
+        alpha3: "aus",
+        numeric: Some(36),
+        short_name: "Australia",
+        official_name: "Australia",
+        continent: "Oceania",
+        subregion: "Australia and New Zealand",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "aw",
+        alpha3: "abw",
+        numeric: Some(533),
+        short_name: "Aruba",
+        official_name: "Aruba",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ax",
+        alpha3: "ala",
+        numeric: Some(248),
+        short_name: "Aland Islands",
+        official_name: "Åland Islands",
+        continent: "Europe",
+        subregion: "Northern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "az",
+        alpha3: "aze",
+        numeric: Some(31),
+        short_name: "Azerbaijan",
+        official_name: "Republic of Azerbaijan",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ba",
+        alpha3: "bih",
+        numeric: Some(70),
+        short_name: "Bosnia and Herzegovina",
+        official_name: "Republic of Bosnia and Herzegovina",
+        continent: "Europe",
+        subregion: "Southern Europe",
+        aliases: &["Bosnia", "Bosnia-Herzegovina"],
+    },
+    Country {
+        alpha2: "bb",
+        alpha3: "brb",
+        numeric: Some(52),
+        short_name: "Barbados",
+        official_name: "Barbados",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "bd",
+        alpha3: "bgd",
+        numeric: Some(50),
+        short_name: "Bangladesh",
+        official_name: "People's Republic of Bangladesh",
+        continent: "Asia",
+        subregion: "Southern Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "be",
+        alpha3: "bel",
+        numeric: Some(56),
+        short_name: "Belgium",
+        official_name: "Kingdom of Belgium",
+        continent: "Europe",
+        subregion: "Western Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "bf",
+        alpha3: "bfa",
+        numeric: Some(854),
+        short_name: "Burkina Faso",
+        official_name: "Burkina Faso",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "bg",
+        alpha3: "bgr",
+        numeric: Some(100),
+        short_name: "Bulgaria",
+        official_name: "Republic of Bulgaria",
+        continent: "Europe",
+        subregion: "Eastern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "bh",
+        alpha3: "bhr",
+        numeric: Some(48),
+        short_name: "Bahrain",
+        official_name: "Kingdom of Bahrain",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "bi",
+        alpha3: "bdi",
+        numeric: Some(108),
+        short_name: "Burundi",
+        official_name: "Republic of Burundi",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "bj",
+        alpha3: "ben",
+        numeric: Some(204),
+        short_name: "Benin",
+        official_name: "Republic of Benin",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "bl",
+        alpha3: "blm",
+        numeric: Some(652),
+        short_name: "Saint Barthelemy",
+        official_name: "Saint Barthélemy",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "bm",
+        alpha3: "bmu",
+        numeric: Some(60),
+        short_name: "Bermuda",
+        official_name: "Bermuda",
+        continent: "North America",
+        subregion: "Northern America",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "bn",
+        alpha3: "brn",
+        numeric: Some(96),
+        short_name: "Brunei",
+        official_name: "Brunei Darussalam",
+        continent: "Asia",
+        subregion: "South-eastern Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "bo",
+        alpha3: "bol",
+        numeric: Some(68),
+        short_name: "Bolivia",
+        official_name: "Plurinational State of Bolivia",
+        continent: "South America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &["Bolivia, Plurinational State of"],
+    },
+    Country {
+        alpha2: "bq",
+        alpha3: "bes",
+        numeric: Some(535),
+        short_name: "Bonaire, Sint Eustatius, and Saba",
+        official_name: "Bonaire, Sint Eustatius and Saba",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &["Caribbean Netherlands"],
+    },
+    Country {
+        alpha2: "br",
+        alpha3: "bra",
+        numeric: Some(76),
+        short_name: "Brazil",
+        official_name: "Federative Republic of Brazil",
+        continent: "South America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "bs",
+        alpha3: "bhs",
+        numeric: Some(44),
+        short_name: "Bahamas",
+        official_name: "Commonwealth of the Bahamas",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &["The Bahamas"],
+    },
+    Country {
+        alpha2: "bt",
+        alpha3: "btn",
+        numeric: Some(64),
+        short_name: "Bhutan",
+        official_name: "Kingdom of Bhutan",
+        continent: "Asia",
+        subregion: "Southern Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "bv",
+        alpha3: "bvt",
+        numeric: Some(74),
+        short_name: "Bouvet Island",
+        official_name: "Bouvet Island",
+        continent: "South America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "bw",
+        alpha3: "bwa",
+        numeric: Some(72),
+        short_name: "Botswana",
+        official_name: "Republic of Botswana",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "by",
+        alpha3: "blr",
+        numeric: Some(112),
+        short_name: "Belarus",
+        official_name: "Republic of Belarus",
+        continent: "Europe",
+        subregion: "Eastern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "bz",
+        alpha3: "blz",
+        numeric: Some(84),
+        short_name: "Belize",
+        official_name: "Belize",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ca",
+        alpha3: "can",
+        numeric: Some(124),
+        short_name: "Canada",
+        official_name: "Canada",
+        continent: "North America",
+        subregion: "Northern America",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "cc",
+        alpha3: "cck",
+        numeric: Some(166),
+        short_name: "Cocos (Keeling) Islands",
+        official_name: "Cocos (Keeling) Islands",
+        continent: "Oceania",
+        subregion: "Australia and New Zealand",
+        aliases: &["Cocos Islands"],
+    },
+    Country {
+        alpha2: "cd",
+        alpha3: "cod",
+        numeric: Some(180),
+        short_name: "Democratic Republic of the Congo",
+        official_name: "Congo, The Democratic Republic of the",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[
+            "Democratic Republic of Congo",
+            "DR Congo",
+            "DRC",
+            "Congo (Kinshasa)",
+            "Congo-Kinshasa",
+            "Zaire",
+        ],
+    },
+    Country {
+        alpha2: "cf",
+        alpha3: "caf",
+        numeric: Some(140),
+        short_name: "Central African Republic",
+        official_name: "Central African Republic",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "cg",
+        alpha3: "cog",
+        numeric: Some(178),
+        short_name: "Republic of the Congo",
+        official_name: "Republic of the Congo",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[
+            "Congo",
+            "Republic of Congo",
+            "Congo (Brazzaville)",
+            "Congo-Brazzaville",
+        ],
+    },
+    Country {
+        alpha2: "ch",
+        alpha3: "che",
+        numeric: Some(756),
+        short_name: "Switzerland",
+        official_name: "Swiss Confederation",
+        continent: "Europe",
+        subregion: "Western Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ci",
+        alpha3: "civ",
+        numeric: Some(384),
+        short_name: "Ivory Coast",
+        official_name: "Republic of Côte d'Ivoire",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &["Côte d'Ivoire"],
+    },
+    Country {
+        alpha2: "ck",
+        alpha3: "cok",
+        numeric: Some(184),
+        short_name: "Cook Islands",
+        official_name: "Cook Islands",
+        continent: "Oceania",
+        subregion: "Polynesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "cl",
+        alpha3: "chl",
+        numeric: Some(152),
+        short_name: "Chile",
+        official_name: "Republic of Chile",
+        continent: "South America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "cm",
+        alpha3: "cmr",
+        numeric: Some(120),
+        short_name: "Cameroon",
+        official_name: "Republic of Cameroon",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "cn",
+        alpha3: "chn",
+        numeric: Some(156),
+        short_name: "China",
+        official_name: "People's Republic of China",
+        continent: "Asia",
+        subregion: "Eastern Asia",
+        aliases: &["PRC", "Mainland China"],
+    },
+    Country {
+        alpha2: "co",
+        alpha3: "col",
+        numeric: Some(170),
+        short_name: "Colombia",
+        official_name: "Republic of Colombia",
+        continent: "South America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "cr",
+        alpha3: "cri",
+        numeric: Some(188),
+        short_name: "Costa Rica",
+        official_name: "Republic of Costa Rica",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "cu",
+        alpha3: "cub",
+        numeric: Some(192),
+        short_name: "Cuba",
+        official_name: "Republic of Cuba",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "cv",
+        alpha3: "cpv",
+        numeric: Some(132),
+        short_name: "Cape Verde",
+        official_name: "Republic of Cabo Verde",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &["Cabo Verde"],
+    },
+    Country {
+        alpha2: "cw",
+        alpha3: "cuw",
+        numeric: Some(531),
+        short_name: "Curacao",
+        official_name: "Curaçao",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "cx",
+        alpha3: "cxr",
+        numeric: Some(162),
+        short_name: "Christmas Island",
+        official_name: "Christmas Island",
+        continent: "Oceania",
+        subregion: "Australia and New Zealand",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "cy",
+        alpha3: "cyp",
+        numeric: Some(196),
+        short_name: "Cyprus",
+        official_name: "Republic of Cyprus",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "cz",
+        alpha3: "cze",
+        numeric: Some(203),
+        short_name: "Czech Republic",
+        official_name: "Czech Republic",
+        continent: "Europe",
+        subregion: "Eastern Europe",
+        aliases: &["Czechia"],
+    },
+    Country {
+        alpha2: "de",
+        alpha3: "deu",
+        numeric: Some(276),
+        short_name: "Germany",
+        official_name: "Federal Republic of Germany",
+        continent: "Europe",
+        subregion: "Western Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "dj",
+        alpha3: "dji",
+        numeric: Some(262),
+        short_name: "Djibouti",
+        official_name: "Republic of Djibouti",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "dk",
+        alpha3: "dnk",
+        numeric: Some(208),
+        short_name: "Denmark",
+        official_name: "Kingdom of Denmark",
+        continent: "Europe",
+        subregion: "Northern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "dm",
+        alpha3: "dma",
+        numeric: Some(212),
+        short_name: "Dominica",
+        official_name: "Commonwealth of Dominica",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "do",
+        alpha3: "dom",
+        numeric: Some(214),
+        short_name: "Dominican Republic",
+        official_name: "Dominican Republic",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "dz",
+        alpha3: "dza",
+        numeric: Some(12),
+        short_name: "Algeria",
+        official_name: "People's Democratic Republic of Algeria",
+        continent: "Africa",
+        subregion: "Northern Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ec",
+        alpha3: "ecu",
+        numeric: Some(218),
+        short_name: "Ecuador",
+        official_name: "Republic of Ecuador",
+        continent: "South America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ee",
+        alpha3: "est",
+        numeric: Some(233),
+        short_name: "Estonia",
+        official_name: "Republic of Estonia",
+        continent: "Europe",
+        subregion: "Northern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "eg",
+        alpha3: "egy",
+        numeric: Some(818),
+        short_name: "Egypt",
+        official_name: "Arab Republic of Egypt",
+        continent: "Africa",
+        subregion: "Northern Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "eh",
+        alpha3: "esh",
+        numeric: Some(732),
+        short_name: "Western Sahara",
+        official_name: "Western Sahara",
+        continent: "Africa",
+        subregion: "Northern Africa",
+        aliases: &["Sahrawi Arab Democratic Republic"],
+    },
+    Country {
+        alpha2: "er",
+        alpha3: "eri",
+        numeric: Some(232),
+        short_name: "Eritrea",
+        official_name: "State of Eritrea",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "es",
+        alpha3: "esp",
+        numeric: Some(724),
+        short_name: "Spain",
+        official_name: "Kingdom of Spain",
+        continent: "Europe",
+        subregion: "Southern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "et",
+        alpha3: "eth",
+        numeric: Some(231),
+        short_name: "Ethiopia",
+        official_name: "Federal Democratic Republic of Ethiopia",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "fi",
+        alpha3: "fin",
+        numeric: Some(246),
+        short_name: "Finland",
+        official_name: "Republic of Finland",
+        continent: "Europe",
+        subregion: "Northern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "fj",
+        alpha3: "fji",
+        numeric: Some(242),
+        short_name: "Fiji",
+        official_name: "Republic of Fiji",
+        continent: "Oceania",
+        subregion: "Melanesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "fk",
+        alpha3: "flk",
+        numeric: Some(238),
+        short_name: "Falkland Islands",
+        official_name: "Falkland Islands (Malvinas)",
+        continent: "South America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "fm",
+        alpha3: "fsm",
+        numeric: Some(583),
+        short_name: "Micronesia",
+        official_name: "Federated States of Micronesia",
+        continent: "Oceania",
+        subregion: "Micronesia",
+        aliases: &["Micronesia, Federated States of"],
+    },
+    Country {
+        alpha2: "fo",
+        alpha3: "fro",
+        numeric: Some(234),
+        short_name: "Faroe Islands",
+        official_name: "Faroe Islands",
+        continent: "Europe",
+        subregion: "Northern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "fr",
+        alpha3: "fra",
+        numeric: Some(250),
+        short_name: "France",
+        official_name: "French Republic",
+        continent: "Europe",
+        subregion: "Western Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ga",
+        alpha3: "gab",
+        numeric: Some(266),
+        short_name: "Gabon",
+        official_name: "Gabonese Republic",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "gb",
+        alpha3: "gbr",
+        numeric: Some(826),
+        short_name: "United Kingdom",
+        official_name: "United Kingdom of Great Britain and Northern Ireland",
+        continent: "Europe",
+        subregion: "Northern Europe",
+        aliases: &[
+            "Great Britain",
+            "Britain",
+            "UK",
+            "U.K.",
+            "England",
+            "Scotland",
+            "Wales",
+            "Northern Ireland",
+        ],
+    },
+    Country {
+        alpha2: "gd",
+        alpha3: "grd",
+        numeric: Some(308),
+        short_name: "Grenada",
+        official_name: "Grenada",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ge",
+        alpha3: "geo",
+        numeric: Some(268),
+        short_name: "Georgia",
+        official_name: "Georgia",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "gf",
+        alpha3: "guf",
+        numeric: Some(254),
+        short_name: "French Guiana",
+        official_name: "French Guiana",
+        continent: "South America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "gg",
+        alpha3: "ggy",
+        numeric: Some(831),
+        short_name: "Guernsey",
+        official_name: "Guernsey",
+        continent: "Europe",
+        subregion: "Northern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "gh",
+        alpha3: "gha",
+        numeric: Some(288),
+        short_name: "Ghana",
+        official_name: "Republic of Ghana",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "gi",
+        alpha3: "gib",
+        numeric: Some(292),
+        short_name: "Gibraltar",
+        official_name: "Gibraltar",
+        continent: "Europe",
+        subregion: "Southern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "gl",
+        alpha3: "grl",
+        numeric: Some(304),
+        short_name: "Greenland",
+        official_name: "Greenland",
+        continent: "North America",
+        subregion: "Northern America",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "gm",
+        alpha3: "gmb",
+        numeric: Some(270),
+        short_name: "Gambia",
+        official_name: "Republic of the Gambia",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &["The Gambia"],
+    },
+    Country {
+        alpha2: "gn",
+        alpha3: "gin",
+        numeric: Some(324),
+        short_name: "Guinea",
+        official_name: "Republic of Guinea",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "gp",
+        alpha3: "glp",
+        numeric: Some(312),
+        short_name: "Guadeloupe",
+        official_name: "Guadeloupe",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "gq",
+        alpha3: "gnq",
+        numeric: Some(226),
+        short_name: "Equatorial Guinea",
+        official_name: "Republic of Equatorial Guinea",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "gr",
+        alpha3: "grc",
+        numeric: Some(300),
+        short_name: "Greece",
+        official_name: "Hellenic Republic",
+        continent: "Europe",
+        subregion: "Southern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "gs",
+        alpha3: "sgs",
+        numeric: Some(239),
+        short_name: "South Georgia and the South Sandwich Islands",
+        official_name: "South Georgia and the South Sandwich Islands",
+        continent: "South America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "gt",
+        alpha3: "gtm",
+        numeric: Some(320),
+        short_name: "Guatemala",
+        official_name: "Republic of Guatemala",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "gu",
+        alpha3: "gum",
+        numeric: Some(316),
+        short_name: "Guam",
+        official_name: "Guam",
+        continent: "Oceania",
+        subregion: "Micronesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "gw",
+        alpha3: "gnb",
+        numeric: Some(624),
+        short_name: "Guinea-Bissau",
+        official_name: "Republic of Guinea-Bissau",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "gy",
+        alpha3: "guy",
+        numeric: Some(328),
+        short_name: "Guyana",
+        official_name: "Republic of Guyana",
+        continent: "South America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "hk",
+        alpha3: "hkg",
+        numeric: Some(344),
+        short_name: "Hong Kong",
+        official_name: "Hong Kong Special Administrative Region of China",
+        continent: "Asia",
+        subregion: "Eastern Asia",
+        aliases: &["Hong Kong SAR"],
+    },
+    Country {
+        alpha2: "hm",
+        alpha3: "hmd",
+        numeric: Some(334),
+        short_name: "Heard Island and McDonald Islands",
+        official_name: "Heard Island and McDonald Islands",
+        continent: "Oceania",
+        subregion: "Australia and New Zealand",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "hn",
+        alpha3: "hnd",
+        numeric: Some(340),
+        short_name: "Honduras",
+        official_name: "Republic of Honduras",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "hr",
+        alpha3: "hrv",
+        numeric: Some(191),
+        short_name: "Croatia",
+        official_name: "Republic of Croatia",
+        continent: "Europe",
+        subregion: "Southern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ht",
+        alpha3: "hti",
+        numeric: Some(332),
+        short_name: "Haiti",
+        official_name: "Republic of Haiti",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "hu",
+        alpha3: "hun",
+        numeric: Some(348),
+        short_name: "Hungary",
+        official_name: "Hungary",
+        continent: "Europe",
+        subregion: "Eastern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "id",
+        alpha3: "idn",
+        numeric: Some(360),
+        short_name: "Indonesia",
+        official_name: "Republic of Indonesia",
+        continent: "Asia",
+        subregion: "South-eastern Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ie",
+        alpha3: "irl",
+        numeric: Some(372),
+        short_name: "Ireland",
+        official_name: "Ireland",
+        continent: "Europe",
+        subregion: "Northern Europe",
+        aliases: &["Republic of Ireland"],
+    },
+    Country {
+        alpha2: "il",
+        alpha3: "isr",
+        numeric: Some(376),
+        short_name: "Israel",
+        official_name: "State of Israel",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "im",
+        alpha3: "imn",
+        numeric: Some(833),
+        short_name: "Isle of Man",
+        official_name: "Isle of Man",
+        continent: "Europe",
+        subregion: "Northern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "in",
+        alpha3: "ind",
+        numeric: Some(356),
+        short_name: "India",
+        official_name: "Republic of India",
+        continent: "Asia",
+        subregion: "Southern Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "io",
+        alpha3: "iot",
+        numeric: Some(86),
+        short_name: "British Indian Ocean Territory",
+        official_name: "British Indian Ocean Territory",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "iq",
+        alpha3: "irq",
+        numeric: Some(368),
+        short_name: "Iraq",
+        official_name: "Republic of Iraq",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ir",
+        alpha3: "irn",
+        numeric: Some(364),
+        short_name: "Iran",
+        official_name: "Islamic Republic of Iran",
+        continent: "Asia",
+        subregion: "Southern Asia",
+        aliases: &["Iran, Islamic Republic of"],
+    },
+    Country {
+        alpha2: "is",
+        alpha3: "isl",
+        numeric: Some(352),
+        short_name: "Iceland",
+        official_name: "Republic of Iceland",
+        continent: "Europe",
+        subregion: "Northern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "it",
+        alpha3: "ita",
+        numeric: Some(380),
+        short_name: "Italy",
+        official_name: "Italian Republic",
+        continent: "Europe",
+        subregion: "Southern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "je",
+        alpha3: "jey",
+        numeric: Some(832),
+        short_name: "Jersey",
+        official_name: "Jersey",
+        continent: "Europe",
+        subregion: "Northern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "jm",
+        alpha3: "jam",
+        numeric: Some(388),
+        short_name: "Jamaica",
+        official_name: "Jamaica",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "jo",
+        alpha3: "jor",
+        numeric: Some(400),
+        short_name: "Jordan",
+        official_name: "Hashemite Kingdom of Jordan",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "jp",
+        alpha3: "jpn",
+        numeric: Some(392),
+        short_name: "Japan",
+        official_name: "Japan",
+        continent: "Asia",
+        subregion: "Eastern Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ke",
+        alpha3: "ken",
+        numeric: Some(404),
+        short_name: "Kenya",
+        official_name: "Republic of Kenya",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "kg",
+        alpha3: "kgz",
+        numeric: Some(417),
+        short_name: "Kyrgyzstan",
+        official_name: "Kyrgyz Republic",
+        continent: "Asia",
+        subregion: "Central Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "kh",
+        alpha3: "khm",
+        numeric: Some(116),
+        short_name: "Cambodia",
+        official_name: "Kingdom of Cambodia",
+        continent: "Asia",
+        subregion: "South-eastern Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ki",
+        alpha3: "kir",
+        numeric: Some(296),
+        short_name: "Kiribati",
+        official_name: "Republic of Kiribati",
+        continent: "Oceania",
+        subregion: "Micronesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "km",
+        alpha3: "com",
+        numeric: Some(174),
+        short_name: "Comoros",
+        official_name: "Union of the Comoros",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "kn",
+        alpha3: "kna",
+        numeric: Some(659),
+        short_name: "Saint Kitts and Nevis",
+        official_name: "Saint Kitts and Nevis",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &["St. Kitts and Nevis", "Saint Kitts"],
+    },
+    Country {
+        alpha2: "kp",
+        alpha3: "prk",
+        numeric: Some(408),
+        short_name: "North Korea",
+        official_name: "Democratic People's Republic of Korea",
+        continent: "Asia",
+        subregion: "Eastern Asia",
+        aliases: &[
+            "Korea, Democratic People's Republic of",
+            "DPRK",
+            "Korea, North",
+        ],
+    },
+    Country {
+        alpha2: "kr",
+        alpha3: "kor",
+        numeric: Some(410),
+        short_name: "South Korea",
+        official_name: "Korea, Republic of",
+        continent: "Asia",
+        subregion: "Eastern Asia",
+        aliases: &["Republic of Korea", "Korea, South"],
+    },
+    Country {
+        alpha2: "kw",
+        alpha3: "kwt",
+        numeric: Some(414),
+        short_name: "Kuwait",
+        official_name: "State of Kuwait",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ky",
+        alpha3: "cym",
+        numeric: Some(136),
+        short_name: "Cayman Islands",
+        official_name: "Cayman Islands",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "kz",
+        alpha3: "kaz",
+        numeric: Some(398),
+        short_name: "Kazakhstan",
+        official_name: "Republic of Kazakhstan",
+        continent: "Asia",
+        subregion: "Central Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "la",
+        alpha3: "lao",
+        numeric: Some(418),
+        short_name: "Laos",
+        official_name: "Lao People's Democratic Republic",
+        continent: "Asia",
+        subregion: "South-eastern Asia",
+        aliases: &["Lao PDR"],
+    },
+    Country {
+        alpha2: "lb",
+        alpha3: "lbn",
+        numeric: Some(422),
+        short_name: "Lebanon",
+        official_name: "Lebanese Republic",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "lc",
+        alpha3: "lca",
+        numeric: Some(662),
+        short_name: "Saint Lucia",
+        official_name: "Saint Lucia",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &["St. Lucia"],
+    },
+    Country {
+        alpha2: "li",
+        alpha3: "lie",
+        numeric: Some(438),
+        short_name: "Liechtenstein",
+        official_name: "Principality of Liechtenstein",
+        continent: "Europe",
+        subregion: "Western Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "lk",
+        alpha3: "lka",
+        numeric: Some(144),
+        short_name: "Sri Lanka",
+        official_name: "Democratic Socialist Republic of Sri Lanka",
+        continent: "Asia",
+        subregion: "Southern Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "lr",
+        alpha3: "lbr",
+        numeric: Some(430),
+        short_name: "Liberia",
+        official_name: "Republic of Liberia",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ls",
+        alpha3: "lso",
+        numeric: Some(426),
+        short_name: "Lesotho",
+        official_name: "Kingdom of Lesotho",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "lt",
+        alpha3: "ltu",
+        numeric: Some(440),
+        short_name: "Lithuania",
+        official_name: "Republic of Lithuania",
+        continent: "Europe",
+        subregion: "Northern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "lu",
+        alpha3: "lux",
+        numeric: Some(442),
+        short_name: "Luxembourg",
+        official_name: "Grand Duchy of Luxembourg",
+        continent: "Europe",
+        subregion: "Western Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "lv",
+        alpha3: "lva",
+        numeric: Some(428),
+        short_name: "Latvia",
+        official_name: "Republic of Latvia",
+        continent: "Europe",
+        subregion: "Northern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ly",
+        alpha3: "lby",
+        numeric: Some(434),
+        short_name: "Libya",
+        official_name: "Libya",
+        continent: "Africa",
+        subregion: "Northern Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ma",
+        alpha3: "mar",
+        numeric: Some(504),
+        short_name: "Morocco",
+        official_name: "Kingdom of Morocco",
+        continent: "Africa",
+        subregion: "Northern Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "mc",
+        alpha3: "mco",
+        numeric: Some(492),
+        short_name: "Monaco",
+        official_name: "Principality of Monaco",
+        continent: "Europe",
+        subregion: "Western Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "md",
+        alpha3: "mda",
+        numeric: Some(498),
+        short_name: "Moldova",
+        official_name: "Republic of Moldova",
+        continent: "Europe",
+        subregion: "Eastern Europe",
+        aliases: &["Moldova, Republic of"],
+    },
+    Country {
+        alpha2: "me",
+        alpha3: "mne",
+        numeric: Some(499),
+        short_name: "Montenegro",
+        official_name: "Montenegro",
+        continent: "Europe",
+        subregion: "Southern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "mf",
+        alpha3: "maf",
+        numeric: Some(663),
+        short_name: "Saint Martin",
+        official_name: "Saint Martin (French part)",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "mg",
+        alpha3: "mdg",
+        numeric: Some(450),
+        short_name: "Madagascar",
+        official_name: "Republic of Madagascar",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "mh",
+        alpha3: "mhl",
+        numeric: Some(584),
+        short_name: "Marshall Islands",
+        official_name: "Republic of the Marshall Islands",
+        continent: "Oceania",
+        subregion: "Micronesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "mk",
+        alpha3: "mkd",
+        numeric: Some(807),
+        short_name: "North Macedonia",
+        official_name: "Republic of North Macedonia",
+        continent: "Europe",
+        subregion: "Southern Europe",
+        aliases: &["Macedonia"],
+    },
+    Country {
+        alpha2: "ml",
+        alpha3: "mli",
+        numeric: Some(466),
+        short_name: "Mali",
+        official_name: "Republic of Mali",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "mm",
+        alpha3: "mmr",
+        numeric: Some(104),
+        short_name: "Myanmar",
+        official_name: "Republic of Myanmar",
+        continent: "Asia",
+        subregion: "South-eastern Asia",
+        aliases: &["Burma"],
+    },
+    Country {
+        alpha2: "mn",
+        alpha3: "mng",
+        numeric: Some(496),
+        short_name: "Mongolia",
+        official_name: "Mongolia",
+        continent: "Asia",
+        subregion: "Eastern Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "mo",
+        alpha3: "mac",
+        numeric: Some(446),
+        short_name: "Macau",
+        official_name: "Macao Special Administrative Region of China",
+        continent: "Asia",
+        subregion: "Eastern Asia",
+        aliases: &["Macao"],
+    },
+    Country {
+        alpha2: "mp",
+        alpha3: "mnp",
+        numeric: Some(580),
+        short_name: "Northern Mariana Islands",
+        official_name: "Commonwealth of the Northern Mariana Islands",
+        continent: "Oceania",
+        subregion: "Micronesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "mq",
+        alpha3: "mtq",
+        numeric: Some(474),
+        short_name: "Martinique",
+        official_name: "Martinique",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "mr",
+        alpha3: "mrt",
+        numeric: Some(478),
+        short_name: "Mauritania",
+        official_name: "Islamic Republic of Mauritania",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ms",
+        alpha3: "msr",
+        numeric: Some(500),
+        short_name: "Montserrat",
+        official_name: "Montserrat",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "mt",
+        alpha3: "mlt",
+        numeric: Some(470),
+        short_name: "Malta",
+        official_name: "Republic of Malta",
+        continent: "Europe",
+        subregion: "Southern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "mu",
+        alpha3: "mus",
+        numeric: Some(480),
+        short_name: "Mauritius",
+        official_name: "Republic of Mauritius",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "mv",
+        alpha3: "mdv",
+        numeric: Some(462),
+        short_name: "Maldives",
+        official_name: "Republic of Maldives",
+        continent: "Asia",
+        subregion: "Southern Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "mw",
+        alpha3: "mwi",
+        numeric: Some(454),
+        short_name: "Malawi",
+        official_name: "Republic of Malawi",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "mx",
+        alpha3: "mex",
+        numeric: Some(484),
+        short_name: "Mexico",
+        official_name: "United Mexican States",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "my",
+        alpha3: "mys",
+        numeric: Some(458),
+        short_name: "Malaysia",
+        official_name: "Malaysia",
+        continent: "Asia",
+        subregion: "South-eastern Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "mz",
+        alpha3: "moz",
+        numeric: Some(508),
+        short_name: "Mozambique",
+        official_name: "Republic of Mozambique",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "na",
+        alpha3: "nam",
+        numeric: Some(516),
+        short_name: "Namibia",
+        official_name: "Republic of Namibia",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "nc",
+        alpha3: "ncl",
+        numeric: Some(540),
+        short_name: "New Caledonia",
+        official_name: "New Caledonia",
+        continent: "Oceania",
+        subregion: "Melanesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ne",
+        alpha3: "ner",
+        numeric: Some(562),
+        short_name: "Niger",
+        official_name: "Republic of the Niger",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "nf",
+        alpha3: "nfk",
+        numeric: Some(574),
+        short_name: "Norfolk Island",
+        official_name: "Norfolk Island",
+        continent: "Oceania",
+        subregion: "Australia and New Zealand",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ng",
+        alpha3: "nga",
+        numeric: Some(566),
+        short_name: "Nigeria",
+        official_name: "Federal Republic of Nigeria",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ni",
+        alpha3: "nic",
+        numeric: Some(558),
+        short_name: "Nicaragua",
+        official_name: "Republic of Nicaragua",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "nl",
+        alpha3: "nld",
+        numeric: Some(528),
+        short_name: "Netherlands",
+        official_name: "Kingdom of the Netherlands",
+        continent: "Europe",
+        subregion: "Western Europe",
+        aliases: &["Holland"],
+    },
+    Country {
+        alpha2: "no",
+        alpha3: "nor",
+        numeric: Some(578),
+        short_name: "Norway",
+        official_name: "Kingdom of Norway",
+        continent: "Europe",
+        subregion: "Northern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "np",
+        alpha3: "npl",
+        numeric: Some(524),
+        short_name: "Nepal",
+        official_name: "Federal Democratic Republic of Nepal",
+        continent: "Asia",
+        subregion: "Southern Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "nr",
+        alpha3: "nru",
+        numeric: Some(520),
+        short_name: "Nauru",
+        official_name: "Republic of Nauru",
+        continent: "Oceania",
+        subregion: "Micronesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "nu",
+        alpha3: "niu",
+        numeric: Some(570),
+        short_name: "Niue",
+        official_name: "Niue",
+        continent: "Oceania",
+        subregion: "Polynesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "nz",
+        alpha3: "nzl",
+        numeric: Some(554),
+        short_name: "New Zealand",
+        official_name: "New Zealand",
+        continent: "Oceania",
+        subregion: "Australia and New Zealand",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "om",
+        alpha3: "omn",
+        numeric: Some(512),
+        short_name: "Oman",
+        official_name: "Sultanate of Oman",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "pa",
+        alpha3: "pan",
+        numeric: Some(591),
+        short_name: "Panama",
+        official_name: "Republic of Panama",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "pe",
+        alpha3: "per",
+        numeric: Some(604),
+        short_name: "Peru",
+        official_name: "Republic of Peru",
+        continent: "South America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "pf",
+        alpha3: "pyf",
+        numeric: Some(258),
+        short_name: "French Polynesia",
+        official_name: "French Polynesia",
+        continent: "Oceania",
+        subregion: "Polynesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "pg",
+        alpha3: "png",
+        numeric: Some(598),
+        short_name: "Papua New Guinea",
+        official_name: "Independent State of Papua New Guinea",
+        continent: "Oceania",
+        subregion: "Melanesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ph",
+        alpha3: "phl",
+        numeric: Some(608),
+        short_name: "Philippines",
+        official_name: "Republic of the Philippines",
+        continent: "Asia",
+        subregion: "South-eastern Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "pk",
+        alpha3: "pak",
+        numeric: Some(586),
+        short_name: "Pakistan",
+        official_name: "Islamic Republic of Pakistan",
+        continent: "Asia",
+        subregion: "Southern Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "pl",
+        alpha3: "pol",
+        numeric: Some(616),
+        short_name: "Poland",
+        official_name: "Republic of Poland",
+        continent: "Europe",
+        subregion: "Eastern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "pm",
+        alpha3: "spm",
+        numeric: Some(666),
+        short_name: "Saint Pierre and Miquelon",
+        official_name: "Saint Pierre and Miquelon",
+        continent: "North America",
+        subregion: "Northern America",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "pn",
+        alpha3: "pcn",
+        numeric: Some(612),
+        short_name: "Pitcairn Islands",
+        official_name: "Pitcairn",
+        continent: "Oceania",
+        subregion: "Polynesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "pr",
+        alpha3: "pri",
+        numeric: Some(630),
+        short_name: "Puerto Rico",
+        official_name: "Puerto Rico",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ps",
+        alpha3: "pse",
+        numeric: Some(275),
+        short_name: "Palestine",
+        official_name: "State of Palestine",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &[
+            "Palestine, State of",
+            "Palestinian Territories",
+            "Palestinian Territory",
+        ],
+    },
+    Country {
+        alpha2: "pt",
+        alpha3: "prt",
+        numeric: Some(620),
+        short_name: "Portugal",
+        official_name: "Portuguese Republic",
+        continent: "Europe",
+        subregion: "Southern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "pw",
+        alpha3: "plw",
+        numeric: Some(585),
+        short_name: "Palau",
+        official_name: "Republic of Palau",
+        continent: "Oceania",
+        subregion: "Micronesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "py",
+        alpha3: "pry",
+        numeric: Some(600),
+        short_name: "Paraguay",
+        official_name: "Republic of Paraguay",
+        continent: "South America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "qa",
+        alpha3: "qat",
+        numeric: Some(634),
+        short_name: "Qatar",
+        official_name: "State of Qatar",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "re",
+        alpha3: "reu",
+        numeric: Some(638),
+        short_name: "Reunion",
+        official_name: "Réunion",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ro",
+        alpha3: "rou",
+        numeric: Some(642),
+        short_name: "Romania",
+        official_name: "Romania",
+        continent: "Europe",
+        subregion: "Eastern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "rs",
+        alpha3: "srb",
+        numeric: Some(688),
+        short_name: "Serbia",
+        official_name: "Republic of Serbia",
+        continent: "Europe",
+        subregion: "Southern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ru",
+        alpha3: "rus",
+        numeric: Some(643),
+        short_name: "Russia",
+        official_name: "Russian Federation",
+        continent: "Europe",
+        subregion: "Eastern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "rw",
+        alpha3: "rwa",
+        numeric: Some(646),
+        short_name: "Rwanda",
+        official_name: "Rwandese Republic",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "sa",
+        alpha3: "sau",
+        numeric: Some(682),
+        short_name: "Saudi Arabia",
+        official_name: "Kingdom of Saudi Arabia",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "sb",
+        alpha3: "slb",
+        numeric: Some(90),
+        short_name: "Solomon Islands",
+        official_name: "Solomon Islands",
+        continent: "Oceania",
+        subregion: "Melanesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "sc",
+        alpha3: "syc",
+        numeric: Some(690),
+        short_name: "Seychelles",
+        official_name: "Republic of Seychelles",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "sd",
+        alpha3: "sdn",
+        numeric: Some(729),
+        short_name: "Sudan",
+        official_name: "Republic of the Sudan",
+        continent: "Africa",
+        subregion: "Northern Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "se",
+        alpha3: "swe",
+        numeric: Some(752),
+        short_name: "Sweden",
+        official_name: "Kingdom of Sweden",
+        continent: "Europe",
+        subregion: "Northern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "sg",
+        alpha3: "sgp",
+        numeric: Some(702),
+        short_name: "Singapore",
+        official_name: "Republic of Singapore",
+        continent: "Asia",
+        subregion: "South-eastern Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "sh",
+        alpha3: "shn",
+        numeric: Some(654),
+        short_name: "Saint Helena",
+        official_name: "Saint Helena, Ascension and Tristan da Cunha",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "si",
+        alpha3: "svn",
+        numeric: Some(705),
+        short_name: "Slovenia",
+        official_name: "Republic of Slovenia",
+        continent: "Europe",
+        subregion: "Southern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "sj",
+        alpha3: "sjm",
+        numeric: Some(744),
+        short_name: "Svalbard and Jan Mayen",
+        official_name: "Svalbard and Jan Mayen",
+        continent: "Europe",
+        subregion: "Northern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "sk",
+        alpha3: "svk",
+        numeric: Some(703),
+        short_name: "Slovakia",
+        official_name: "Slovak Republic",
+        continent: "Europe",
+        subregion: "Eastern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "sl",
+        alpha3: "sle",
+        numeric: Some(694),
+        short_name: "Sierra Leone",
+        official_name: "Republic of Sierra Leone",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "sm",
+        alpha3: "smr",
+        numeric: Some(674),
+        short_name: "San Marino",
+        official_name: "Republic of San Marino",
+        continent: "Europe",
+        subregion: "Southern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "sn",
+        alpha3: "sen",
+        numeric: Some(686),
+        short_name: "Senegal",
+        official_name: "Republic of Senegal",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "so",
+        alpha3: "som",
+        numeric: Some(706),
+        short_name: "Somalia",
+        official_name: "Federal Republic of Somalia",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "sr",
+        alpha3: "sur",
+        numeric: Some(740),
+        short_name: "Suriname",
+        official_name: "Republic of Suriname",
+        continent: "South America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ss",
+        alpha3: "ssd",
+        numeric: Some(728),
+        short_name: "South Sudan",
+        official_name: "Republic of South Sudan",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "st",
+        alpha3: "stp",
+        numeric: Some(678),
+        short_name: "Sao Tome and Principe",
+        official_name: "Democratic Republic of Sao Tome and Principe",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "sv",
+        alpha3: "slv",
+        numeric: Some(222),
+        short_name: "El Salvador",
+        official_name: "Republic of El Salvador",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "sx",
+        alpha3: "sxm",
+        numeric: Some(534),
+        short_name: "Sint Maarten",
+        official_name: "Sint Maarten (Dutch part)",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "sy",
+        alpha3: "syr",
+        numeric: Some(760),
+        short_name: "Syria",
+        official_name: "Syrian Arab Republic",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "sz",
+        alpha3: "swz",
+        numeric: Some(748),
+        short_name: "Eswatini",
+        official_name: "Kingdom of Eswatini",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &["Swaziland"],
+    },
+    Country {
+        alpha2: "tc",
+        alpha3: "tca",
+        numeric: Some(796),
+        short_name: "Turks and Caicos Islands",
+        official_name: "Turks and Caicos Islands",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "td",
+        alpha3: "tcd",
+        numeric: Some(148),
+        short_name: "Chad",
+        official_name: "Republic of Chad",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "tf",
+        alpha3: "atf",
+        numeric: Some(260),
+        short_name: "French Southern Territories",
+        official_name: "French Southern Territories",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &["French Southern and Antarctic Lands"],
+    },
+    Country {
+        alpha2: "tg",
+        alpha3: "tgo",
+        numeric: Some(768),
+        short_name: "Togo",
+        official_name: "Togolese Republic",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "th",
+        alpha3: "tha",
+        numeric: Some(764),
+        short_name: "Thailand",
+        official_name: "Kingdom of Thailand",
+        continent: "Asia",
+        subregion: "South-eastern Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "tj",
+        alpha3: "tjk",
+        numeric: Some(762),
+        short_name: "Tajikistan",
+        official_name: "Republic of Tajikistan",
+        continent: "Asia",
+        subregion: "Central Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "tk",
+        alpha3: "tkl",
+        numeric: Some(772),
+        short_name: "Tokelau",
+        official_name: "Tokelau",
+        continent: "Oceania",
+        subregion: "Polynesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "tl",
+        alpha3: "tls",
+        numeric: Some(626),
+        short_name: "East Timor",
+        official_name: "Democratic Republic of Timor-Leste",
+        continent: "Asia",
+        subregion: "South-eastern Asia",
+        aliases: &["Timor-Leste"],
+    },
+    Country {
+        alpha2: "tm",
+        alpha3: "tkm",
+        numeric: Some(795),
+        short_name: "Turkmenistan",
+        official_name: "Turkmenistan",
+        continent: "Asia",
+        subregion: "Central Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "tn",
+        alpha3: "tun",
+        numeric: Some(788),
+        short_name: "Tunisia",
+        official_name: "Republic of Tunisia",
+        continent: "Africa",
+        subregion: "Northern Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "to",
+        alpha3: "ton",
+        numeric: Some(776),
+        short_name: "Tonga",
+        official_name: "Kingdom of Tonga",
+        continent: "Oceania",
+        subregion: "Polynesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "tr",
+        alpha3: "tur",
+        numeric: Some(792),
+        short_name: "Turkey",
+        official_name: "Republic of Türkiye",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &["Türkiye"],
+    },
+    Country {
+        alpha2: "tt",
+        alpha3: "tto",
+        numeric: Some(780),
+        short_name: "Trinidad and Tobago",
+        official_name: "Republic of Trinidad and Tobago",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "tv",
+        alpha3: "tuv",
+        numeric: Some(798),
+        short_name: "Tuvalu",
+        official_name: "Tuvalu",
+        continent: "Oceania",
+        subregion: "Polynesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "tw",
+        alpha3: "twn",
+        numeric: Some(158),
+        short_name: "Taiwan",
+        official_name: "Republic of China",
+        continent: "Asia",
+        subregion: "Eastern Asia",
+        aliases: &["Taiwan, Province of China"],
+    },
+    Country {
+        alpha2: "tz",
+        alpha3: "tza",
+        numeric: Some(834),
+        short_name: "Tanzania",
+        official_name: "United Republic of Tanzania",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &["Tanzania, United Republic of"],
+    },
+    Country {
+        alpha2: "ua",
+        alpha3: "ukr",
+        numeric: Some(804),
+        short_name: "Ukraine",
+        official_name: "Ukraine",
+        continent: "Europe",
+        subregion: "Eastern Europe",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ug",
+        alpha3: "uga",
+        numeric: Some(800),
+        short_name: "Uganda",
+        official_name: "Republic of Uganda",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "um",
+        alpha3: "umi",
+        numeric: Some(581),
+        short_name: "United States Minor Outlying Islands",
+        official_name: "United States Minor Outlying Islands",
+        continent: "Oceania",
+        subregion: "Micronesia",
+        aliases: &["U.S. Minor Outlying Islands"],
+    },
+    Country {
+        alpha2: "us",
+        alpha3: "usa",
+        numeric: Some(840),
+        short_name: "United States",
+        official_name: "United States of America",
+        continent: "North America",
+        subregion: "Northern America",
+        aliases: &["USA", "U.S.", "U.S.A."],
+    },
+    Country {
+        alpha2: "uy",
+        alpha3: "ury",
+        numeric: Some(858),
+        short_name: "Uruguay",
+        official_name: "Eastern Republic of Uruguay",
+        continent: "South America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "uz",
+        alpha3: "uzb",
+        numeric: Some(860),
+        short_name: "Uzbekistan",
+        official_name: "Republic of Uzbekistan",
+        continent: "Asia",
+        subregion: "Central Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "va",
+        alpha3: "vat",
+        numeric: Some(336),
+        short_name: "Vatican City",
+        official_name: "Holy See (Vatican City State)",
+        continent: "Europe",
+        subregion: "Southern Europe",
+        aliases: &["Holy See", "Vatican"],
+    },
+    Country {
+        alpha2: "vc",
+        alpha3: "vct",
+        numeric: Some(670),
+        short_name: "Saint Vincent and the Grenadines",
+        official_name: "Saint Vincent and the Grenadines",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &["St. Vincent and the Grenadines"],
+    },
+    Country {
+        alpha2: "ve",
+        alpha3: "ven",
+        numeric: Some(862),
+        short_name: "Venezuela",
+        official_name: "Bolivarian Republic of Venezuela",
+        continent: "South America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &["Venezuela, Bolivarian Republic of"],
+    },
+    Country {
+        alpha2: "vg",
+        alpha3: "vgb",
+        numeric: Some(92),
+        short_name: "British Virgin Islands",
+        official_name: "British Virgin Islands",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &["Virgin Islands, British"],
+    },
+    Country {
+        alpha2: "vi",
+        alpha3: "vir",
+        numeric: Some(850),
+        short_name: "United States Virgin Islands",
+        official_name: "Virgin Islands of the United States",
+        continent: "North America",
+        subregion: "Latin America and the Caribbean",
+        aliases: &["Virgin Islands, U.S.", "U.S. Virgin Islands"],
+    },
+    Country {
+        alpha2: "vn",
+        alpha3: "vnm",
+        numeric: Some(704),
+        short_name: "Vietnam",
+        official_name: "Socialist Republic of Viet Nam",
+        continent: "Asia",
+        subregion: "South-eastern Asia",
+        aliases: &["Viet Nam"],
+    },
+    Country {
+        alpha2: "vu",
+        alpha3: "vut",
+        numeric: Some(548),
+        short_name: "Vanuatu",
+        official_name: "Republic of Vanuatu",
+        continent: "Oceania",
+        subregion: "Melanesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "wf",
+        alpha3: "wlf",
+        numeric: Some(876),
+        short_name: "Wallis and Futuna",
+        official_name: "Wallis and Futuna",
+        continent: "Oceania",
+        subregion: "Polynesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "ws",
+        alpha3: "wsm",
+        numeric: Some(882),
+        short_name: "Samoa",
+        official_name: "Independent State of Samoa",
+        continent: "Oceania",
+        subregion: "Polynesia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "xk",
+        alpha3: "xkx",
+        numeric: None,
+        short_name: "Kosovo",
+        official_name: "Kosovo",
+        continent: "Europe",
+        subregion: "Southern Europe",
+        aliases: &["Republic of Kosovo"],
+    },
+    Country {
+        alpha2: "ye",
+        alpha3: "yem",
+        numeric: Some(887),
+        short_name: "Yemen",
+        official_name: "Republic of Yemen",
+        continent: "Asia",
+        subregion: "Western Asia",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "yt",
+        alpha3: "myt",
+        numeric: Some(175),
+        short_name: "Mayotte",
+        official_name: "Mayotte",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "za",
+        alpha3: "zaf",
+        numeric: Some(710),
+        short_name: "South Africa",
+        official_name: "Republic of South Africa",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "zm",
+        alpha3: "zmb",
+        numeric: Some(894),
+        short_name: "Zambia",
+        official_name: "Republic of Zambia",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+    Country {
+        alpha2: "zw",
+        alpha3: "zwe",
+        numeric: Some(716),
+        short_name: "Zimbabwe",
+        official_name: "Republic of Zimbabwe",
+        continent: "Africa",
+        subregion: "Sub-Saharan Africa",
+        aliases: &[],
+    },
+];
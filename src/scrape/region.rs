@@ -1,8 +1,18 @@
 use crate::prelude::*;
-use crate::{db::util::get_db_pool, service::var_service::get_docker_volume};
+use crate::scrape::scraper::wikidata::get_former_figure_cutoff;
+use crate::{
+    db::{
+        index::{load_keyphrase_index, save_keyphrase_index},
+        policy::{load_territory_policy, TerritoryPolicy},
+        util::get_db_pool,
+    },
+    service::var_service::{get_docker_volume, get_optional_var},
+};
 use async_std::task;
+use chrono::Utc;
 use once_cell::sync::Lazy;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use sha2::{Digest, Sha256};
 use sqlx::Row;
 use std::{
     collections::{HashMap, HashSet},
@@ -12,70 +22,100 @@ use std::{
 };
 
 struct RegionKeyphrases {
-    pub automated: Option<Vec<String>>,         // src/db/keyphrase.rs
-    pub names: Option<Vec<&'static str>>,       // Manual
-    pub demonyms: Option<Vec<&'static str>>,    // Manual
+    pub automated: Option<Vec<(String, String)>>, // src/db/keyphrase.rs, as (keyphrase, category) pairs
+    pub acronyms: Option<Vec<&'static str>>,      // Manual, matched case-sensitively as whole words
+    pub names: Option<Vec<&'static str>>,         // Manual
+    pub demonyms: Option<Vec<&'static str>>,      // Manual
     pub enterprises: Option<Vec<&'static str>>, // Manual: https://companiesmarketcap.com/all-countries/
     pub misc: Option<Vec<&'static str>>,        // Manual
 }
 
+// Compiled (keyphrase, category) pairs of each region.
+pub type CompiledMap = Vec<(Vec<(&'static str, &'static str)>, &'static str)>;
+
+// Bump when keyphrase processing changes so that stale keyphrase indexes are recompiled.
+const KEYPHRASE_INDEX_VERSION: &str = "3";
+
 impl RegionKeyphrases {
-    pub fn get_region_vec(self) -> Vec<&'static str> {
-        let mut region_vec: Vec<&'static str> = Vec::new();
-        // First-order administrative regions ≥ 490k population, capitals, cities ≥ 290k population...
-        // ...heads of state and government, largest private enterprises, and billionaires ≥ 9.9B final worth USD.z
-        if let Some(automated) = self.automated {
-            for s in automated {
-                region_vec.push(Box::leak(s.into_boxed_str()));
+    // Automated keyphrases are covered by get_keyphrase_version instead.
+    fn update_hasher(&self, hasher: &mut Sha256) {
+        for (category, keyphrases) in self.get_manual_categories() {
+            if let Some(keyphrases) = keyphrases {
+                hasher.update(category);
+                keyphrases
+                    .iter()
+                    .for_each(|s| hasher.update(format!("\0{}", s)));
             }
         }
-        if let Some(names) = self.names {
-            region_vec.extend(names);
-        }
-        if let Some(demonyms) = self.demonyms {
-            region_vec.extend(demonyms);
-        }
+    }
+
+    fn get_manual_categories(&self) -> [(&'static str, &Option<Vec<&'static str>>); 5] {
+        [
+            ("acronym", &self.acronyms),
+            ("name", &self.names),
+            ("demonym", &self.demonyms),
+            ("enterprise", &self.enterprises),
+            ("misc", &self.misc),
+        ]
+    }
+
+    pub fn get_region_vec(self) -> Vec<(&'static str, &'static str)> {
+        let mut region_vec: Vec<(&'static str, &'static str)> = Vec::new();
         // Public enterprises ≥ 9.9B market cap USD.
-        if let Some(enterprises) = self.enterprises {
-            region_vec.extend(enterprises);
+        // Misc: positions of power, legislative bodies, institutions, buildings, political groups, ideologies, ethnic groups, cultural regions, identifier names, etc.
+        for (category, keyphrases) in self.get_manual_categories() {
+            if category == "acronym" {
+                continue; // See KEYPHRASE_ACRONYM_MAP.
+            }
+
+            if let Some(keyphrases) = keyphrases {
+                region_vec.extend(keyphrases.iter().map(|s| (*s, category)));
+            }
         }
-        // Positions of power, legislative bodies, institutions, buildings, political groups, ideologies, ethnic groups, cultural regions, identifier names, etc.
-        if let Some(misc) = self.misc {
-            region_vec.extend(misc);
+        // First-order administrative regions ≥ 490k population, capitals, cities ≥ 290k population...
+        // ...heads of state and government, largest private enterprises, and billionaires ≥ BILLIONAIRE_MIN_NET_WORTH.
+        if let Some(automated) = self.automated {
+            for (s, category) in automated {
+                region_vec.push((Box::leak(s.into_boxed_str()), get_static_category(category)));
+            }
         }
 
-        region_vec.retain(|s| !s.is_empty());
-        region_vec.sort_by_key(|a| a.len());
-        let mut i = 0;
-        while i < region_vec.len() {
-            let mut j = i + 1;
-            while j < region_vec.len() {
-                if region_vec[j] == region_vec[i] {
-                    j += 1;
-                    continue;
-                }
-
-                if region_vec[j].contains(region_vec[i]) {
-                    //tracing::debug!("Removing region-level substring-containing string {} because of substring {}", region_vec[j], region_vec[i]);
-                    region_vec.remove(j);
-                } else {
-                    j += 1;
-                }
+        region_vec.retain(|(s, _)| !s.is_empty());
+        // The sort is stable, so a manual keyphrase keeps its category over an automated duplicate.
+        region_vec.sort_by_key(|(s, _)| s.len());
+        // Removes keyphrases that contain a shorter kept one, duplicates included, by looking up each keyphrase's substrings
+        // of the kept lengths rather than comparing it with every kept keyphrase.
+        let mut kept_keyphrases: HashSet<&'static str> = HashSet::new();
+        let mut kept_lengths: Vec<usize> = Vec::new();
+        region_vec.retain(|(s, _)| {
+            let contains_kept = kept_lengths.iter().any(|length| {
+                (0..=s.len() - length).any(|start| {
+                    s.get(start..start + length)
+                        .is_some_and(|substring| kept_keyphrases.contains(substring))
+                })
+            });
+            if contains_kept {
+                //tracing::debug!("Removing region-level substring-containing string {}", s);
+                return false;
             }
 
-            i += 1;
-        }
+            kept_keyphrases.insert(*s);
+            if kept_lengths.last() != Some(&s.len()) {
+                kept_lengths.push(s.len());
+            }
+            true
+        });
 
-        let mut short_strings: Vec<&'static str> = Vec::new();
-        region_vec.iter_mut().for_each(|s| {
+        let mut short_strings: Vec<(&'static str, &'static str)> = Vec::new();
+        region_vec.iter_mut().for_each(|(s, category)| {
             if s.len() < 4 {
                 if *s == "ain" || *s == "bid" || *s == "est" {
                     return;
                 }
-                short_strings.push(Box::leak(format!("'{}'", s).into_boxed_str()));
-                short_strings.push(Box::leak(format!("\"{}\"", s).into_boxed_str()));
-                short_strings.push(Box::leak(format!("{}.", s).into_boxed_str()));
-                short_strings.push(Box::leak(format!("{},", s).into_boxed_str()));
+                short_strings.push((Box::leak(format!("'{}'", s).into_boxed_str()), category));
+                short_strings.push((Box::leak(format!("\"{}\"", s).into_boxed_str()), category));
+                short_strings.push((Box::leak(format!("{}.", s).into_boxed_str()), category));
+                short_strings.push((Box::leak(format!("{},", s).into_boxed_str()), category));
                 *s = Box::leak(format!(" {} ", s).into_boxed_str());
             }
         });
@@ -83,21 +123,18 @@ impl RegionKeyphrases {
 
         // " inc" is a catch-all for other types here, where I include this string when the enterprise name is ambiguous (ex. 'apple' -> 'apple inc').
         // Enterprise type changes do not have to be tracked this way.
-        let mut enterprise_types: Vec<&'static str> = Vec::new();
-        region_vec.iter().for_each(|s| {
+        let mut enterprise_types: Vec<(&'static str, &'static str)> = Vec::new();
+        region_vec.iter().for_each(|(s, category)| {
             let Some(stripped) = s.strip_suffix(" inc") else {
                 return;
             };
 
-            enterprise_types.push(Box::leak(format!("{}, inc", stripped).into_boxed_str()));
-            enterprise_types.push(Box::leak(format!("{} ltd", stripped).into_boxed_str()));
-            enterprise_types.push(Box::leak(format!("{}, ltd", stripped).into_boxed_str()));
-            enterprise_types.push(Box::leak(format!("{} limited", stripped).into_boxed_str()));
-            enterprise_types.push(Box::leak(format!("{}, limited", stripped).into_boxed_str()));
-            enterprise_types.push(Box::leak(format!("{} plc", stripped).into_boxed_str()));
-            enterprise_types.push(Box::leak(format!("{}, plc", stripped).into_boxed_str()));
-            enterprise_types.push(Box::leak(format!("{} llc", stripped).into_boxed_str()));
-            enterprise_types.push(Box::leak(format!("{}, llc", stripped).into_boxed_str()));
+            for enterprise_type in ENTERPRISE_TYPES {
+                enterprise_types.push((
+                    Box::leak(format!("{}{}", stripped, enterprise_type).into_boxed_str()),
+                    category,
+                ));
+            }
         });
         region_vec.extend(enterprise_types.iter().cloned());
 
@@ -105,52 +142,198 @@ impl RegionKeyphrases {
     }
 }
 
-async fn build_region_map() -> Result<HashMap<String, Vec<String>>> {
-    let db_path = format!("{}/region_db.sqlite", get_docker_volume().await?);
+// Suffixes that variants of an enterprise keyphrase ending in " inc" are also matched with.
+const ENTERPRISE_TYPES: [&str; 9] = [
+    ", inc",
+    " ltd",
+    ", ltd",
+    " limited",
+    ", limited",
+    " plc",
+    ", plc",
+    " llc",
+    ", llc",
+];
+
+// The keyphrase that a padded short string, such as "'uk'" or "uk.", or an enterprise variant was made from.
+pub fn get_base_keyphrase(keyphrase: &str) -> &str {
+    let keyphrase = keyphrase.trim_matches([' ', '\'', '"', '.', ',']);
+    ENTERPRISE_TYPES
+        .iter()
+        .chain([" inc"].iter())
+        .find_map(|enterprise_type| keyphrase.strip_suffix(enterprise_type))
+        .unwrap_or(keyphrase)
+}
+
+// Avoids leaking a copy of the category of every automated keyphrase.
+fn get_static_category(category: String) -> &'static str {
+    match category.as_str() {
+        "name" => "name",
+        "demonym" => "demonym",
+        "place" => "place",
+        "figure" => "figure",
+        "official" => "official",
+        "billionaire" => "billionaire",
+        "enterprise" => "enterprise",
+        "sanctioned" => "sanctioned",
+        "misc" => "misc",
+        _ => Box::leak(category.into_boxed_str()),
+    }
+}
+
+// Automated (keyphrase, category) pairs of each region code.
+type AutomatedMap = HashMap<String, Vec<(String, String)>>;
+
+// Coding of disputed territories from TERRITORY_POLICY_PATH, applied to automated keyphrases and classified regions.
+pub static TERRITORY_POLICY: Lazy<TerritoryPolicy> = Lazy::new(|| {
+    let policy_path = match task::block_on(get_optional_var("TERRITORY_POLICY_PATH")) {
+        Ok(policy_path) => policy_path,
+        Err(e) => {
+            tracing::error!("Failed to get territory policy path: {:?}", e);
+            None
+        }
+    };
+
+    match load_territory_policy(policy_path.as_deref()) {
+        Ok(policy) => policy,
+        Err(e) => {
+            tracing::error!("Failed to load territory policy: {:?}", e);
+            TerritoryPolicy::default()
+        }
+    }
+});
+
+// Keyphrases in effect: former office holders drop out once FORMER_FIGURE_DAYS have passed since their term ended. Takes
+// the current time, then the cutoff.
+const VALIDITY_FILTER: &str =
+    "(valid_from IS NULL OR valid_from <= ?) AND (valid_until IS NULL OR valid_until > ?)";
+
+// Version of the automated keyphrases that the compiled maps are built from: each source's keyphrase count, latest
+// addition, and latest refresh that changed it, the time-bounded keyphrases in effect, and the territory policy. It only
+// changes when a source's keyphrases do, or when an office holder comes into or drops out of effect.
+async fn get_keyphrase_version(docker_volume: &str) -> Result<String> {
+    let db_path = format!("{}/region_db.sqlite", docker_volume);
+    let db_path = Path::new(&db_path);
+    let pool = get_db_pool(db_path).await?;
+    let mut version = TERRITORY_POLICY.get_version();
+    let sources = sqlx::query(
+        "SELECT keyphrases.source, COUNT(*), MAX(keyphrases.added_at), source_refreshes.changed_at
+        FROM keyphrases LEFT JOIN source_refreshes ON source_refreshes.source = keyphrases.source
+        GROUP BY keyphrases.source ORDER BY keyphrases.source",
+    )
+    .fetch_all(&pool)
+    .await?;
+    for row in &sources {
+        version.push_str(&format!(
+            "\n{}:{}:{}:{}",
+            row.try_get::<String, _>(0)?,
+            row.try_get::<i64, _>(1)?,
+            row.try_get::<i64, _>(2)?,
+            row.try_get::<Option<i64>, _>(3)?.unwrap_or_default()
+        ));
+    }
+    let bounded = sqlx::query(&format!(
+        "SELECT region_code, phrase FROM keyphrases
+        WHERE (valid_from IS NOT NULL OR valid_until IS NOT NULL) AND {VALIDITY_FILTER}
+        ORDER BY region_code, phrase"
+    ))
+    .bind(Utc::now().timestamp())
+    .bind(get_former_figure_cutoff().await?)
+    .fetch_all(&pool)
+    .await?;
+    for row in &bounded {
+        version.push_str(&format!(
+            "\n{}\0{}",
+            row.try_get::<String, _>(0)?,
+            row.try_get::<String, _>(1)?
+        ));
+    }
+
+    Ok(version)
+}
+
+async fn build_region_map(docker_volume: &str) -> Result<AutomatedMap> {
+    let db_path = format!("{}/region_db.sqlite", docker_volume);
     let db_path = Path::new(&db_path);
     let pool = get_db_pool(db_path).await?;
-    let mut region_map = HashMap::new();
-    let rows = sqlx::query("SELECT * FROM regions")
-        .fetch_all(&pool)
-        .await?;
+    let mut region_map: AutomatedMap = HashMap::new();
+    let rows = sqlx::query(&format!(
+        "SELECT region_code, phrase, category, subregion_code FROM keyphrases
+        WHERE {VALIDITY_FILTER} ORDER BY region_code, phrase"
+    ))
+    .bind(Utc::now().timestamp())
+    .bind(get_former_figure_cutoff().await?)
+    .fetch_all(&pool)
+    .await?;
     for row in &rows {
-        region_map.insert(row.get(0), vec![row.get(1)]);
+        let (region_code, subregion_code): (&str, Option<&str>) = (row.get(0), row.get(3));
+        for region_code in TERRITORY_POLICY.get_keyphrase_codes(region_code, subregion_code) {
+            region_map
+                .entry(region_code.to_string())
+                .or_default()
+                .push((row.get(1), row.get(2)));
+        }
     }
 
     Ok(region_map)
 }
 
+async fn build_subregion_map(docker_volume: &str) -> Result<AutomatedMap> {
+    let db_path = format!("{}/region_db.sqlite", docker_volume);
+    let db_path = Path::new(&db_path);
+    let pool = get_db_pool(db_path).await?;
+    let mut subregion_map: AutomatedMap = HashMap::new();
+    let rows = sqlx::query(
+        "SELECT subregion_code, phrase, category FROM keyphrases WHERE subregion_code IS NOT NULL
+        ORDER BY subregion_code, phrase",
+    )
+    .fetch_all(&pool)
+    .await?;
+    for row in &rows {
+        // Subregions coded as another country no longer belong to the one GeoNames lists.
+        let subregion_code: &str = row.get(0);
+        let region_code = subregion_code.split('-').next().unwrap_or_default();
+        if !TERRITORY_POLICY
+            .get_keyphrase_codes(region_code, Some(subregion_code))
+            .contains(&region_code)
+        {
+            continue;
+        }
+
+        subregion_map
+            .entry(subregion_code.to_string())
+            .or_default()
+            .push((row.get(1), row.get(2)));
+    }
+
+    Ok(subregion_map)
+}
+
 fn get_automated_keyphrases(
-    region_map: &HashMap<String, Vec<String>>,
+    region_map: &AutomatedMap,
     region_code: &str,
-) -> Option<Vec<String>> {
+) -> Option<Vec<(String, String)>> {
     region_map.get(region_code).map(|g| {
         g.iter()
-            .flat_map(|s| {
+            .map(|(s, category)| {
                 // For automatically generated keyphrases that are also subwords, add spaces around them.
-                let mut results = Vec::new();
-                for s in s.split(',') {
-                    let s = s.trim().to_string();
-                    match s.as_str() {
-                        "acre" | "arges" | "gard" | "marche" | "teni" | "wien" => {
-                            results.push(" ".to_owned() + &s.clone() + " ");
-                        }
-                        _ => results.push(s.clone()),
+                let s = s.trim();
+                let s = match s {
+                    "acre" | "arges" | "gard" | "marche" | "teni" | "wien" => {
+                        " ".to_owned() + s + " "
                     }
-                }
-                results
+                    _ => s.to_string(),
+                };
+                (s, category.clone())
             })
             .collect::<Vec<_>>()
     })
 }
 
-fn remove_ambiguities(
-    vec: Vec<(Vec<&'static str>, &'static str)>,
-    blacklist: HashSet<&'static str>,
-) -> Vec<(Vec<&'static str>, &'static str)> {
+fn remove_ambiguities(vec: CompiledMap, blacklist: HashSet<&'static str>) -> CompiledMap {
     // let mut map = HashMap::new();
     // for (key, _) in &vec {
-    //     for s in key {
+    //     for (s, _) in key {
     //         let count = map.entry(s).or_insert(0);
     //         *count += 1;
     //         if *count > 1 {
@@ -159,23 +342,10 @@ fn remove_ambiguities(
     //     }
     // }
 
-    let vec: Vec<(Vec<&'static str>, &'static str)> = vec
-        .into_par_iter()
-        .map(|(keys, value)| {
-            // Removes duplicate strings.
-            let unique_keys: Vec<&'static str> = keys
-                .clone()
-                .into_par_iter()
-                .collect::<HashSet<_>>()
-                .into_par_iter()
-                .collect();
-            (unique_keys, value)
-        })
-        .collect();
     let mut all_strings: Vec<&'static str> = vec
         .clone()
         .into_par_iter()
-        .flat_map(|(keys, _)| keys.clone())
+        .flat_map(|(keys, _)| keys.into_par_iter().map(|(key, _)| key).collect::<Vec<_>>())
         .collect();
     let all_strings_copy = all_strings.clone();
     let mut to_remove = blacklist;
@@ -200,40 +370,381 @@ fn remove_ambiguities(
         .map(|(keys, value)| {
             let new_keys = keys
                 .into_par_iter()
-                .filter(|key| all_strings.contains(key))
+                .filter(|(key, _)| all_strings.contains(key))
                 .collect();
             (new_keys, value)
         })
         .collect()
 }
 
-pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> = Lazy::new(|| {
+fn get_index_hash(
+    version: &str,
+    manual: &[(RegionKeyphrases, &'static str)],
+    blacklist: &[&'static str],
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(KEYPHRASE_INDEX_VERSION);
+    hasher.update(format!("\n{}", version));
+    for (keyphrases, region) in manual {
+        hasher.update(format!("\n{}", region));
+        keyphrases.update_hasher(&mut hasher);
+    }
+    hasher.update("\nblacklist");
+    blacklist
+        .iter()
+        .for_each(|s| hasher.update(format!("\0{}", s)));
+
+    format!("{:x}", hasher.finalize())
+}
+
+fn compile_region_map(
+    map: Vec<(RegionKeyphrases, &'static str)>,
+    blacklist: Vec<&'static str>,
+) -> CompiledMap {
+    let map = map
+        .into_par_iter()
+        .map(|(keyphrases, region)| (keyphrases.get_region_vec(), region))
+        .collect();
+
+    remove_ambiguities(map, blacklist.into_par_iter().collect())
+}
+
+pub static KEYPHRASE_REGION_MAP: Lazy<CompiledMap> = Lazy::new(|| {
     // Please contribute on https://github.com/lichenaut/anbamap-api !
-    let region_map = task::block_on(build_region_map());
-    let region_map = match region_map {
-        Ok(map) => map,
+    let docker_volume = match task::block_on(get_docker_volume()) {
+        Ok(docker_volume) => docker_volume,
+        Err(e) => {
+            tracing::error!("Failed to build region map: {:?}", e);
+            return Vec::new();
+        }
+    };
+    let version = match task::block_on(get_keyphrase_version(&docker_volume)) {
+        Ok(version) => version,
         Err(e) => {
             tracing::error!("Failed to build region map: {:?}", e);
             return Vec::new();
         }
     };
 
-    let map = vec![
+    let index_path = format!("{}/keyphrase_index.bin", docker_volume);
+    let manual = get_region_keyphrases(&HashMap::new());
+    get_compiled_map(Path::new(&index_path), &version, &manual, || {
+        let region_map = task::block_on(build_region_map(&docker_volume))?;
+        Ok(get_region_keyphrases(&region_map))
+    })
+});
+
+// Keyphrases of first-order administrative divisions, keyed by GeoNames admin1 subregion codes such as "ua-14" whose
+// prefix is the country code (see get_subregion_code).
+pub static KEYPHRASE_SUBREGION_MAP: Lazy<CompiledMap> = Lazy::new(|| {
+    let docker_volume = match task::block_on(get_docker_volume()) {
+        Ok(docker_volume) => docker_volume,
+        Err(e) => {
+            tracing::error!("Failed to build subregion map: {:?}", e);
+            return Vec::new();
+        }
+    };
+    let version = match task::block_on(get_keyphrase_version(&docker_volume)) {
+        Ok(version) => version,
+        Err(e) => {
+            tracing::error!("Failed to build subregion map: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    let index_path = format!("{}/subregion_index.bin", docker_volume);
+    get_compiled_map(Path::new(&index_path), &version, &[], || {
+        let subregion_map = task::block_on(build_subregion_map(&docker_volume))?;
+        let mut subregion_codes: Vec<&String> = subregion_map.keys().collect();
+        subregion_codes.sort_unstable(); // Keeps the compiled map in the same order across runs.
+        Ok(subregion_codes
+            .into_iter()
+            .map(|subregion_code| {
+                (
+                    RegionKeyphrases {
+                        automated: get_automated_keyphrases(&subregion_map, subregion_code),
+                        acronyms: None,
+                        names: None,
+                        demonyms: None,
+                        enterprises: None,
+                        misc: None,
+                    },
+                    &*Box::leak(subregion_code.clone().into_boxed_str()),
+                )
+            })
+            .collect())
+    })
+});
+
+// Acronyms are kept out of the compiled maps, since they are matched against the original case of the text.
+pub static KEYPHRASE_ACRONYM_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> = Lazy::new(|| {
+    get_region_keyphrases(&HashMap::new())
+        .into_iter()
+        .filter_map(|(keyphrases, region)| Some((keyphrases.acronyms?, region)))
+        .collect()
+});
+
+pub struct Place {
+    pub geoname_id: String,
+    pub name: &'static str,
+    pub region: &'static str,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+// GeoNames places with coordinates, keyed by their keyphrases. Names that are too short to match on their own are padded.
+pub static KEYPHRASE_PLACE_MAP: Lazy<Vec<(&'static str, Place)>> = Lazy::new(|| {
+    let docker_volume = match task::block_on(get_docker_volume()) {
+        Ok(docker_volume) => docker_volume,
+        Err(e) => {
+            tracing::error!("Failed to build place map: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    match task::block_on(build_place_map(&docker_volume)) {
+        Ok(map) => map,
+        Err(e) => {
+            tracing::error!("Failed to build place map: {:?}", e);
+            Vec::new()
+        }
+    }
+});
+
+async fn build_place_map(docker_volume: &str) -> Result<Vec<(&'static str, Place)>> {
+    let db_path = format!("{}/region_db.sqlite", docker_volume);
+    let db_path = Path::new(&db_path);
+    let pool = get_db_pool(db_path).await?;
+    let rows = sqlx::query(
+        "SELECT phrase, region_code, source_id, latitude, longitude, subregion_code FROM keyphrases
+        WHERE latitude IS NOT NULL AND longitude IS NOT NULL ORDER BY region_code, phrase",
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    let mut place_map = Vec::new();
+    for row in &rows {
+        let name: String = row.try_get(0)?;
+        let keyphrase = match name.len() < 4 {
+            true => format!(" {} ", name),
+            false => name.clone(),
+        };
+        let region_code: String = row.try_get(1)?;
+        let subregion_code: Option<String> = row.try_get(5)?;
+        let region = TERRITORY_POLICY.get_keyphrase_codes(&region_code, subregion_code.as_deref())
+            [0]
+        .to_string();
+        place_map.push((
+            &*Box::leak(keyphrase.into_boxed_str()),
+            Place {
+                geoname_id: row.try_get(2)?,
+                name: Box::leak(name.into_boxed_str()),
+                region: Box::leak(region.into_boxed_str()),
+                latitude: row.try_get(3)?,
+                longitude: row.try_get(4)?,
+            },
+        ));
+    }
+
+    Ok(place_map)
+}
+
+pub struct Entity {
+    pub entity_id: String,
+    pub name: &'static str,
+    pub entity_type: &'static str,
+    pub region: &'static str,
+}
+
+// Heads of state, officials, billionaires, and enterprises, keyed by their keyphrases. Manual enterprises have no source ID,
+// so theirs is made from their region code and keyphrase. Only keyphrases that KEYPHRASE_REGION_MAP kept for the entity's
+// region are used, so entities agree with the regions found in the same text.
+pub static KEYPHRASE_ENTITY_MAP: Lazy<Vec<(&'static str, Entity)>> = Lazy::new(|| {
+    let docker_volume = match task::block_on(get_docker_volume()) {
+        Ok(docker_volume) => docker_volume,
+        Err(e) => {
+            tracing::error!("Failed to build entity map: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    match task::block_on(build_entity_map(&docker_volume)) {
+        Ok(map) => map,
+        Err(e) => {
+            tracing::error!("Failed to build entity map: {:?}", e);
+            Vec::new()
+        }
+    }
+});
+
+async fn build_entity_map(docker_volume: &str) -> Result<Vec<(&'static str, Entity)>> {
+    let db_path = format!("{}/region_db.sqlite", docker_volume);
+    let db_path = Path::new(&db_path);
+    let pool = get_db_pool(db_path).await?;
+    let rows = sqlx::query(&format!(
+        "SELECT phrase, region_code, category, source_id FROM keyphrases
+        WHERE category IN ('figure', 'official', 'billionaire', 'enterprise') AND {VALIDITY_FILTER}
+        ORDER BY region_code, phrase"
+    ))
+    .bind(Utc::now().timestamp())
+    .bind(get_former_figure_cutoff().await?)
+    .fetch_all(&pool)
+    .await?;
+
+    let mut entities: Vec<(String, String, String, String)> = Vec::new();
+    for row in &rows {
+        entities.push((
+            row.try_get(0)?,
+            row.try_get(1)?,
+            row.try_get(2)?,
+            row.try_get(3)?,
+        ));
+    }
+    for (region, keyphrases) in get_manual_keyphrases() {
+        for (category, keyphrase) in keyphrases {
+            if category == "enterprise" {
+                entities.push((
+                    keyphrase.to_string(),
+                    region.to_string(),
+                    category.to_string(),
+                    format!("{region}:{keyphrase}"),
+                ));
+            }
+        }
+    }
+
+    let region_keyphrases: HashSet<(&str, &str)> = KEYPHRASE_REGION_MAP
+        .iter()
+        .flat_map(|(keyphrases, region)| keyphrases.iter().map(|(s, _)| (*s, *region)))
+        .collect();
+    let mut entity_map = Vec::new();
+    for (name, region, category, entity_id) in entities {
+        let keyphrase = match name.len() < 4 {
+            true => format!(" {} ", name),
+            false => name.clone(),
+        };
+        let regions = TERRITORY_POLICY.get_keyphrase_codes(&region, None);
+        if !regions
+            .iter()
+            .any(|region| region_keyphrases.contains(&(keyphrase.as_str(), *region)))
+        {
+            continue;
+        }
+
+        let region = regions[0].to_string();
+        entity_map.push((
+            &*Box::leak(keyphrase.into_boxed_str()),
+            Entity {
+                entity_id,
+                name: Box::leak(name.into_boxed_str()),
+                entity_type: get_static_category(category),
+                region: Box::leak(region.into_boxed_str()),
+            },
+        ));
+    }
+
+    Ok(entity_map)
+}
+
+// Loads the compiled map from its index, building, compiling, and saving it when the index is missing or outdated.
+fn get_compiled_map(
+    index_path: &Path,
+    version: &str,
+    manual: &[(RegionKeyphrases, &'static str)],
+    build: impl FnOnce() -> Result<Vec<(RegionKeyphrases, &'static str)>>,
+) -> CompiledMap {
+    let mut blacklist = get_blacklist();
+    blacklist.sort_unstable();
+    blacklist.dedup();
+
+    let hash = get_index_hash(version, manual, &blacklist);
+    match load_keyphrase_index(index_path, &hash) {
+        Ok(Some(index)) => {
+            return index
+                .into_iter()
+                .map(|(keyphrases, region)| {
+                    let keyphrases = keyphrases
+                        .into_iter()
+                        .map(|(s, category)| {
+                            (
+                                &*Box::leak(s.into_boxed_str()),
+                                get_static_category(category),
+                            )
+                        })
+                        .collect();
+                    (keyphrases, &*Box::leak(region.into_boxed_str()))
+                })
+                .collect();
+        }
+        Ok(None) => (),
+        Err(e) => tracing::error!("Failed to load keyphrase index: {:?}", e),
+    }
+
+    let map = match build() {
+        Ok(map) => map,
+        Err(e) => {
+            tracing::error!("Failed to build keyphrase map: {:?}", e);
+            return Vec::new();
+        }
+    };
+    let map = compile_region_map(map, blacklist);
+    let index = map
+        .iter()
+        .map(|(keyphrases, region)| {
+            (
+                keyphrases
+                    .iter()
+                    .map(|(s, category)| (s.to_string(), category.to_string()))
+                    .collect(),
+                region.to_string(),
+            )
+        })
+        .collect();
+    if let Err(e) = save_keyphrase_index(index_path, &hash, &index) {
+        tracing::error!("Failed to save keyphrase index: {:?}", e);
+    }
+
+    map
+}
+
+// Manually maintained keyphrases of each region as (category, keyphrase) pairs.
+pub fn get_manual_keyphrases() -> Vec<(&'static str, Vec<(&'static str, &'static str)>)> {
+    get_region_keyphrases(&HashMap::new())
+        .into_iter()
+        .map(|(keyphrases, region)| {
+            let manual_keyphrases = keyphrases
+                .get_manual_categories()
+                .into_iter()
+                .flat_map(|(category, keyphrases)| {
+                    keyphrases
+                        .iter()
+                        .flatten()
+                        .map(move |keyphrase| (category, *keyphrase))
+                })
+                .collect();
+            (region, manual_keyphrases)
+        })
+        .collect()
+}
+
+fn get_region_keyphrases(region_map: &AutomatedMap) -> Vec<(RegionKeyphrases, &'static str)> {
+    vec![
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ad"),
+                automated: get_automated_keyphrases(region_map, "ad"),
+                acronyms: None,
                 names: Some(vec!["andorra"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["general syndic", "council of the valleys"]),
-            }
-            .get_region_vec(),
+            },
             "ad",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ae"),
-                names: Some(vec!["united arab emirates", "uae"]),
+                automated: get_automated_keyphrases(region_map, "ae"),
+                acronyms: Some(vec!["UAE"]),
+                names: Some(vec!["united arab emirates"]),
                 demonyms: Some(vec!["emirati"]),
                 enterprises: Some(vec![
                     "international holding co",
@@ -253,57 +764,57 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "mashreqbank",
                 ]),
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "ae",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "af"),
+                automated: get_automated_keyphrases(region_map, "af"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["afghan"]),
                 enterprises: None,
                 misc: Some(vec!["taliban"]),
-            }
-            .get_region_vec(),
+            },
             "af",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ag"),
+                automated: get_automated_keyphrases(region_map, "ag"),
+                acronyms: None,
                 names: Some(vec!["antigua", "barbuda", "a&b"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["ablp", "united progressive party"]),
-            }
-            .get_region_vec(),
+            },
             "ag",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ai"),
+                automated: get_automated_keyphrases(region_map, "ai"),
+                acronyms: None,
                 names: Some(vec!["anguilla"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "ai",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "al"),
+                automated: get_automated_keyphrases(region_map, "al"),
+                acronyms: None,
                 names: Some(vec!["albania"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["kuvendi"]),
-            }
-            .get_region_vec(),
+            },
             "al",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "am"),
+                automated: get_automated_keyphrases(region_map, "am"),
+                acronyms: None,
                 names: Some(vec![
                     "armenia ",
                     "armenia'",
@@ -314,35 +825,35 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                 demonyms: Some(vec!["armenian"]),
                 enterprises: None,
                 misc: Some(vec!["azgayin zhoghov"]),
-            }
-            .get_region_vec(),
+            },
             "am",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ao"),
+                automated: get_automated_keyphrases(region_map, "ao"),
+                acronyms: Some(vec!["MPLA"]),
                 names: Some(vec!["angola"]),
                 demonyms: None,
                 enterprises: None,
-                misc: Some(vec![" mpla ", "unita"]),
-            }
-            .get_region_vec(),
+                misc: Some(vec!["unita"]),
+            },
             "ao",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "aq"),
+                automated: get_automated_keyphrases(region_map, "aq"),
+                acronyms: None,
                 names: Some(vec!["antarctica"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["mcmurdo"]),
-            }
-            .get_region_vec(),
+            },
             "aq",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ar"),
+                automated: get_automated_keyphrases(region_map, "ar"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["argentin"]),
                 enterprises: Some(vec!["mercadolibre", "ypf", "yacimientos petroliferos"]),
@@ -354,35 +865,35 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "peronis",
                     "kirchneris",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "ar",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "as"),
+                automated: get_automated_keyphrases(region_map, "as"),
+                acronyms: None,
                 names: Some(vec!["american samoa"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "as",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "at"),
+                automated: get_automated_keyphrases(region_map, "at"),
+                acronyms: None,
                 names: Some(vec!["austria", "oesterreich"]),
                 demonyms: None,
                 enterprises: Some(vec!["verbund", "erste group", "erste bank", "omv"]),
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "at",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "au"),
+                automated: get_automated_keyphrases(region_map, "au"),
+                acronyms: None,
                 names: Some(vec!["australia"]),
                 demonyms: Some(vec!["aussie"]),
                 enterprises: Some(vec![
@@ -420,46 +931,46 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "sgh",
                 ]),
                 misc: Some(vec!["aborigin", "assange"]),
-            }
-            .get_region_vec(),
+            },
             "au",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "aw"),
+                automated: get_automated_keyphrases(region_map, "aw"),
+                acronyms: None,
                 names: Some(vec!["aruba"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "aw",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ax"),
+                automated: get_automated_keyphrases(region_map, "ax"),
+                acronyms: None,
                 names: Some(vec!["aland"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "ax",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "az"),
+                automated: get_automated_keyphrases(region_map, "az"),
+                acronyms: None,
                 names: Some(vec!["azerbaijan"]),
                 demonyms: Some(vec!["azeri"]),
                 enterprises: None,
                 misc: Some(vec!["milli majlis", "democratic reforms party"]),
-            }
-            .get_region_vec(),
+            },
             "az",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ba"),
+                automated: get_automated_keyphrases(region_map, "ba"),
+                acronyms: None,
                 names: Some(vec!["bosnia", "srpska", "brcko"]),
                 demonyms: Some(vec!["herzegovin"]),
                 enterprises: None,
@@ -467,68 +978,68 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "alliance of independent social democrats",
                     "party of democratic action",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "ba",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "bb"),
+                automated: get_automated_keyphrases(region_map, "bb"),
+                acronyms: None,
                 names: Some(vec!["barbados"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "bb",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "bd"),
+                automated: get_automated_keyphrases(region_map, "bd"),
+                acronyms: None,
                 names: Some(vec!["bangladesh"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["jatiya sangsad", "awami league", "jatiya party"]),
-            }
-            .get_region_vec(),
+            },
             "bd",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "be"),
+                automated: get_automated_keyphrases(region_map, "be"),
+                acronyms: None,
                 names: Some(vec!["belgium"]),
                 demonyms: Some(vec!["belgian"]),
                 enterprises: Some(vec!["anheuser-busch", "kbc", "ucb", "d'leteren", "gbl"]),
                 misc: Some(vec!["flemish", "walloon"]),
-            }
-            .get_region_vec(),
+            },
             "be",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "bf"),
+                automated: get_automated_keyphrases(region_map, "bf"),
+                acronyms: None,
                 names: Some(vec!["burkina faso"]),
                 demonyms: Some(vec!["burkinabe", "burkinese"]),
                 enterprises: None,
                 misc: Some(vec!["mpsr"]),
-            }
-            .get_region_vec(),
+            },
             "bf",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "bg"),
+                automated: get_automated_keyphrases(region_map, "bg"),
+                acronyms: None,
                 names: Some(vec!["bulgaria"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["narodno sabranie", "gerb"]),
-            }
-            .get_region_vec(),
+            },
             "bg",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "bh"),
+                automated: get_automated_keyphrases(region_map, "bh"),
+                acronyms: None,
                 names: Some(vec!["bahrain"]),
                 demonyms: None,
                 enterprises: Some(vec!["ahli united", "ahli bank"]),
@@ -538,13 +1049,13 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "progressive democratic tribune",
                     "bchr",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "bh",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "bi"),
+                automated: get_automated_keyphrases(region_map, "bi"),
+                acronyms: None,
                 names: Some(vec!["burundi"]),
                 demonyms: None,
                 enterprises: None,
@@ -553,68 +1064,68 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "national congress for liberty",
                     "national congress for freedom",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "bi",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "bj"),
+                automated: get_automated_keyphrases(region_map, "bj"),
+                acronyms: None,
                 names: Some(vec!["benin"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["progressive union for renewal"]),
-            }
-            .get_region_vec(),
+            },
             "bj",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "bl"),
+                automated: get_automated_keyphrases(region_map, "bl"),
+                acronyms: None,
                 names: Some(vec!["saint barthelemy"]),
                 demonyms: Some(vec!["barthelemois"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "bl",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "bm"),
+                automated: get_automated_keyphrases(region_map, "bm"),
+                acronyms: None,
                 names: Some(vec!["bermuda"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "bm",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "bn"),
+                automated: get_automated_keyphrases(region_map, "bn"),
+                acronyms: None,
                 names: None, // Name comes from database.
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "bn",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "bo"),
+                automated: get_automated_keyphrases(region_map, "bo"),
+                acronyms: None,
                 names: Some(vec!["bolivia"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["pluritonal", "plaza murillo"]),
-            }
-            .get_region_vec(),
+            },
             "bo",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "bq"),
+                automated: get_automated_keyphrases(region_map, "bq"),
+                acronyms: None,
                 names: Some(vec![
                     "bonaire",
                     "sint eustatius",
@@ -625,13 +1136,13 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "bq",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "br"),
+                automated: get_automated_keyphrases(region_map, "br"),
+                acronyms: None,
                 names: Some(vec!["brazil", "brasil"]),
                 demonyms: None,
                 enterprises: Some(vec![
@@ -656,79 +1167,79 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "localiza",
                 ]),
                 misc: Some(vec!["planalto", "lula"]),
-            }
-            .get_region_vec(),
+            },
             "br",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "bs"),
+                automated: get_automated_keyphrases(region_map, "bs"),
+                acronyms: None,
                 names: Some(vec!["bahama"]),
                 demonyms: Some(vec!["bahamian"]),
                 enterprises: None,
                 misc: Some(vec!["progressive liberal party", "free national movement"]),
-            }
-            .get_region_vec(),
+            },
             "bs",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "bt"),
+                automated: get_automated_keyphrases(region_map, "bt"),
+                acronyms: None,
                 names: Some(vec!["bhutan"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["druk gyalpo"]),
-            }
-            .get_region_vec(),
+            },
             "bt",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "bv"),
+                automated: get_automated_keyphrases(region_map, "bv"),
+                acronyms: None,
                 names: Some(vec!["bouvet"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "bv",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "bw"),
+                automated: get_automated_keyphrases(region_map, "bw"),
+                acronyms: None,
                 names: Some(vec!["botswana"]),
                 demonyms: Some(vec!["batswana", "motswana"]),
                 enterprises: None,
                 misc: Some(vec!["umbrella for democratic change"]),
-            }
-            .get_region_vec(),
+            },
             "bw",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "by"),
+                automated: get_automated_keyphrases(region_map, "by"),
+                acronyms: None,
                 names: Some(vec!["belarus"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["belaya rus", "ldpb"]),
-            }
-            .get_region_vec(),
+            },
             "by",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "bz"),
+                automated: get_automated_keyphrases(region_map, "bz"),
+                acronyms: None,
                 names: Some(vec!["belize"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["people's united party"]),
-            }
-            .get_region_vec(),
+            },
             "bz",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ca"),
+                automated: get_automated_keyphrases(region_map, "ca"),
+                acronyms: None,
                 names: None, // Name comes from database.
                 demonyms: Some(vec!["canadian"]),
                 enterprises: Some(vec![
@@ -793,25 +1304,25 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "first nations",
                     "trudeau",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "ca",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "cc"),
+                automated: get_automated_keyphrases(region_map, "cc"),
+                acronyms: None,
                 names: Some(vec!["cocos island", "keeling island"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "cc",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "cd"),
-                names: Some(vec!["democratic republic of the congo", "drc", "big congo"]),
+                automated: get_automated_keyphrases(region_map, "cd"),
+                acronyms: Some(vec!["DRC"]),
+                names: Some(vec!["democratic republic of the congo", "big congo"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec![
@@ -822,13 +1333,13 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "fardc",
                     "monusco",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "cd",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "cf"),
+                automated: get_automated_keyphrases(region_map, "cf"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["central african"]),
                 enterprises: None,
@@ -838,24 +1349,24 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "fprc",
                     "anti-balaka",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "cf",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "cg"),
+                automated: get_automated_keyphrases(region_map, "cg"),
+                acronyms: None,
                 names: Some(vec!["little congo"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["congolese party of labour", "upads"]),
-            }
-            .get_region_vec(),
+            },
             "cg",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ch"),
+                automated: get_automated_keyphrases(region_map, "ch"),
+                acronyms: None,
                 names: Some(vec!["switzerland"]),
                 demonyms: Some(vec!["swiss"]),
                 enterprises: Some(vec![
@@ -896,58 +1407,58 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "swatch",
                 ]),
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "ch",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ci"),
+                automated: get_automated_keyphrases(region_map, "ci"),
+                acronyms: None,
                 names: Some(vec!["ivory coast", "cote d'ivoire"]),
                 demonyms: Some(vec!["ivorian"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "ci",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ck"),
+                automated: get_automated_keyphrases(region_map, "ck"),
+                acronyms: None,
                 names: Some(vec!["cook island"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "ck",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "cl"),
+                automated: get_automated_keyphrases(region_map, "cl"),
+                acronyms: None,
                 names: Some(vec!["chile"]),
                 demonyms: None,
                 enterprises: Some(vec!["quimica y minera", "enel americas", "empresas copec"]),
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "cl",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "cm"),
+                automated: get_automated_keyphrases(region_map, "cm"),
+                acronyms: None,
                 names: Some(vec!["cameroon"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["unity palace", "rdpc", "ambazonia"]),
-            }
-            .get_region_vec(),
+            },
             "cm",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "cn"),
-                names: Some(vec!["china", "prc"]),
+                automated: get_automated_keyphrases(region_map, "cn"),
+                acronyms: Some(vec!["CCP", "PRC"]),
+                names: Some(vec!["china"]),
                 demonyms: Some(vec!["chinese"]),
                 enterprises: Some(vec![
                     "tencent",
@@ -1059,24 +1570,24 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "yangtze",
                     "xi",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "cn",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "co"),
+                automated: get_automated_keyphrases(region_map, "co"),
+                acronyms: None,
                 names: Some(vec!["colombia"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["casa de narino", "capitolio nacional", "eln"]),
-            }
-            .get_region_vec(),
+            },
             "co",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "cr"),
+                automated: get_automated_keyphrases(region_map, "cr"),
+                acronyms: None,
                 names: Some(vec!["costa rica"]),
                 demonyms: None,
                 enterprises: None,
@@ -1086,79 +1597,79 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "national liberation party",
                     "verdiblancos",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "cr",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "cu"),
+                automated: get_automated_keyphrases(region_map, "cu"),
+                acronyms: None,
                 names: Some(vec!["cuba"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["national assembly of people's power"]),
-            }
-            .get_region_vec(),
+            },
             "cu",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "cv"),
+                automated: get_automated_keyphrases(region_map, "cv"),
+                acronyms: None,
                 names: Some(vec!["cape verde"]),
                 demonyms: Some(vec!["cabo verdean"]),
                 enterprises: None,
                 misc: Some(vec!["paicv"]),
-            }
-            .get_region_vec(),
+            },
             "cv",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "cw"),
+                automated: get_automated_keyphrases(region_map, "cw"),
+                acronyms: None,
                 names: Some(vec!["curacao"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["mfk", "real alternative party"]),
-            }
-            .get_region_vec(),
+            },
             "cw",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "cx"),
+                automated: get_automated_keyphrases(region_map, "cx"),
+                acronyms: None,
                 names: Some(vec!["christmas island"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "cx",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "cy"),
+                automated: get_automated_keyphrases(region_map, "cy"),
+                acronyms: None,
                 names: Some(vec!["cyprus"]),
                 demonyms: Some(vec!["cypriot"]),
                 enterprises: None,
                 misc: Some(vec!["akel"]),
-            }
-            .get_region_vec(),
+            },
             "cy",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "cz"),
+                automated: get_automated_keyphrases(region_map, "cz"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["czech"]),
                 enterprises: Some(vec!["cez"]),
                 misc: Some(vec!["spolu", "ano 2011"]),
-            }
-            .get_region_vec(),
+            },
             "cz",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "de"),
+                automated: get_automated_keyphrases(region_map, "de"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["german", "deutsche"]),
                 enterprises: Some(vec![
@@ -1206,24 +1717,24 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "evonik",
                 ]),
                 misc: Some(vec!["bundestag", "cdu", "scholz"]),
-            }
-            .get_region_vec(),
+            },
             "de",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "dj"),
+                automated: get_automated_keyphrases(region_map, "dj"),
+                acronyms: None,
                 names: Some(vec!["djibouti"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["union for the presidential majority"]),
-            }
-            .get_region_vec(),
+            },
             "dj",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "dk"),
+                automated: get_automated_keyphrases(region_map, "dk"),
+                acronyms: None,
                 names: Some(vec!["denmark"]),
                 demonyms: Some(vec!["danish", "dane"]),
                 enterprises: Some(vec![
@@ -1243,13 +1754,13 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "demant",
                 ]),
                 misc: Some(vec!["folketing"]),
-            }
-            .get_region_vec(),
+            },
             "dk",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "dm"),
+                automated: get_automated_keyphrases(region_map, "dm"),
+                acronyms: None,
                 names: Some(vec![
                     "dominica ",
                     "dominica'",
@@ -1260,90 +1771,90 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "dm",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "do"),
+                automated: get_automated_keyphrases(region_map, "do"),
+                acronyms: None,
                 names: Some(vec!["dominican republic"]),
                 demonyms: Some(vec!["quisqueyan"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "do",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "dz"),
+                automated: get_automated_keyphrases(region_map, "dz"),
+                acronyms: None,
                 names: Some(vec!["algeria"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["algerie", "fln"]),
-            }
-            .get_region_vec(),
+            },
             "dz",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ec"),
+                automated: get_automated_keyphrases(region_map, "ec"),
+                acronyms: None,
                 names: Some(vec!["ecuador"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["union for hope"]),
-            }
-            .get_region_vec(),
+            },
             "ec",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ee"),
+                automated: get_automated_keyphrases(region_map, "ee"),
+                acronyms: None,
                 names: Some(vec!["estonia"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "ee",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "eg"),
+                automated: get_automated_keyphrases(region_map, "eg"),
+                acronyms: None,
                 names: Some(vec!["egypt"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "eg",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "eh"),
+                automated: get_automated_keyphrases(region_map, "eh"),
+                acronyms: None,
                 names: Some(vec!["western sahara"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["polisario"]),
-            }
-            .get_region_vec(),
+            },
             "eh",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "er"),
+                automated: get_automated_keyphrases(region_map, "er"),
+                acronyms: None,
                 names: Some(vec!["eritrea"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["pfdj"]),
-            }
-            .get_region_vec(),
+            },
             "er",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "es"),
+                automated: get_automated_keyphrases(region_map, "es"),
+                acronyms: None,
                 names: Some(vec!["spain"]),
                 demonyms: Some(vec!["spaniard"]),
                 enterprises: Some(vec![
@@ -1366,13 +1877,13 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "grupo acs",
                 ]),
                 misc: Some(vec!["cortes generales", "psoe", "sumar"]),
-            }
-            .get_region_vec(),
+            },
             "es",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "et"),
+                automated: get_automated_keyphrases(region_map, "et"),
+                acronyms: None,
                 names: Some(vec!["ethiopia"]),
                 demonyms: None,
                 enterprises: None,
@@ -1382,13 +1893,13 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "prosperity party",
                     "national movement of amhara",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "et",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "fi"),
+                automated: get_automated_keyphrases(region_map, "fi"),
+                acronyms: None,
                 names: Some(vec!["finland"]),
                 demonyms: Some(vec!["finn"]),
                 enterprises: Some(vec![
@@ -1404,57 +1915,57 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "metso",
                 ]),
                 misc: Some(vec!["eduskunta", "national coalition party"]),
-            }
-            .get_region_vec(),
+            },
             "fi",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "fj"),
+                automated: get_automated_keyphrases(region_map, "fj"),
+                acronyms: None,
                 names: Some(vec!["fiji"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "fj",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "fk"),
+                automated: get_automated_keyphrases(region_map, "fk"),
+                acronyms: None,
                 names: Some(vec!["falkland", "malvinas"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "fk",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "fm"),
+                automated: get_automated_keyphrases(region_map, "fm"),
+                acronyms: None,
                 names: Some(vec!["micronesia", "fsm"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "fm",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "fo"),
+                automated: get_automated_keyphrases(region_map, "fo"),
+                acronyms: None,
                 names: Some(vec!["faroe island"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["logting"]),
-            }
-            .get_region_vec(),
+            },
             "fo",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "fr"),
+                automated: get_automated_keyphrases(region_map, "fr"),
+                acronyms: None,
                 names: Some(vec!["france"]),
                 demonyms: None,
                 enterprises: Some(vec![
@@ -1503,27 +2014,26 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "eiffage",
                 ]),
                 misc: Some(vec!["macron"]),
-            }
-            .get_region_vec(),
+            },
             "fr",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ga"),
+                automated: get_automated_keyphrases(region_map, "ga"),
+                acronyms: None,
                 names: Some(vec!["gabon"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["ctri"]),
-            }
-            .get_region_vec(),
+            },
             "ga",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "gb"),
+                automated: get_automated_keyphrases(region_map, "gb"),
+                acronyms: Some(vec!["UK"]),
                 names: Some(vec![
                     "united kingdom",
-                    "uk",
                     "britain",
                     "england",
                     "scotland",
@@ -1590,201 +2100,201 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "severn trent",
                 ]),
                 misc: Some(vec!["house of lords", "stormont", "sunak"]),
-            }
-            .get_region_vec(),
+            },
             "gb",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "gd"),
+                automated: get_automated_keyphrases(region_map, "gd"),
+                acronyms: None,
                 names: Some(vec!["grenada"]),
                 demonyms: Some(vec!["grenadian"]),
                 enterprises: None,
                 misc: Some(vec!["rgpf"]),
-            }
-            .get_region_vec(),
+            },
             "gd",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ge"),
+                automated: get_automated_keyphrases(region_map, "ge"),
+                acronyms: None,
                 names: None,
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["abkhaz", "united national movement"]),
-            }
-            .get_region_vec(),
+            },
             "ge",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "gf"),
+                automated: get_automated_keyphrases(region_map, "gf"),
+                acronyms: None,
                 names: Some(vec!["french guiana"]),
                 demonyms: Some(vec!["french guianan", "french guinese"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "fr", // Its own code is 'gf', but it's a region of France (for leaflet purposes).
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "gg"),
+                automated: get_automated_keyphrases(region_map, "gg"),
+                acronyms: None,
                 names: Some(vec!["guernsey"]),
                 demonyms: Some(vec!["giernesiais"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "gg",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "gh"),
+                automated: get_automated_keyphrases(region_map, "gh"),
+                acronyms: None,
                 names: Some(vec!["ghana"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["national democratic congress", "new patriotic party"]),
-            }
-            .get_region_vec(),
+            },
             "gh",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "gi"),
+                automated: get_automated_keyphrases(region_map, "gi"),
+                acronyms: None,
                 names: None, // Name comes from database.
                 demonyms: Some(vec!["llanito"]),
                 enterprises: None,
                 misc: Some(vec!["gslp"]),
-            }
-            .get_region_vec(),
+            },
             "gi",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "gl"),
+                automated: get_automated_keyphrases(region_map, "gl"),
+                acronyms: None,
                 names: Some(vec!["greenland"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["inuit ataqatigiit", "naleraq", "siumut"]),
-            }
-            .get_region_vec(),
+            },
             "gl",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "gm"),
+                automated: get_automated_keyphrases(region_map, "gm"),
+                acronyms: None,
                 names: Some(vec!["gambia"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "gm",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "gn"),
+                automated: get_automated_keyphrases(region_map, "gn"),
+                acronyms: None,
                 names: None,
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["cnrd"]),
-            }
-            .get_region_vec(),
+            },
             "gn",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "gp"),
+                automated: get_automated_keyphrases(region_map, "gp"),
+                acronyms: None,
                 names: Some(vec!["guadeloupe"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "gp",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "gq"),
+                automated: get_automated_keyphrases(region_map, "gq"),
+                acronyms: None,
                 names: Some(vec!["equatorial guinea"]),
                 demonyms: Some(vec!["equatoguinean"]),
                 enterprises: None,
                 misc: Some(vec!["pdge"]),
-            }
-            .get_region_vec(),
+            },
             "gq",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "gr"),
+                automated: get_automated_keyphrases(region_map, "gr"),
+                acronyms: None,
                 names: Some(vec!["greece"]),
                 demonyms: Some(vec!["greek"]),
                 enterprises: None,
                 misc: Some(vec!["helleni", "syriza"]),
-            }
-            .get_region_vec(),
+            },
             "gr",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "gs"),
+                automated: get_automated_keyphrases(region_map, "gs"),
+                acronyms: None,
                 names: Some(vec!["south georgia", "south sandwich"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "gs",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "gt"),
+                automated: get_automated_keyphrases(region_map, "gt"),
+                acronyms: None,
                 names: Some(vec!["guatemala"]),
                 demonyms: Some(vec!["chapin"]),
                 enterprises: None,
                 misc: Some(vec!["semilla"]),
-            }
-            .get_region_vec(),
+            },
             "gt",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "gu"),
+                automated: get_automated_keyphrases(region_map, "gu"),
+                acronyms: None,
                 names: Some(vec!["guam"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "gu",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "gw"),
+                automated: get_automated_keyphrases(region_map, "gw"),
+                acronyms: None,
                 names: Some(vec!["guinea-bissau"]),
                 demonyms: Some(vec!["bissau-guinean"]),
                 enterprises: None,
                 misc: Some(vec!["terra ranka", "paigc", "madem g15", "madem-g15"]),
-            }
-            .get_region_vec(),
+            },
             "gw",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "gy"),
+                automated: get_automated_keyphrases(region_map, "gy"),
+                acronyms: None,
                 names: Some(vec!["guyan"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "gy",
         ),
         (
             RegionKeyphrases {
                 // I am not including "... China ..." keyphrases for this region, as I value the 'China" keyphrase more for the China region.
-                automated: get_automated_keyphrases(&region_map, "hk"),
+                automated: get_automated_keyphrases(region_map, "hk"),
+                acronyms: None,
                 names: Some(vec!["hong kong"]),
                 demonyms: Some(vec!["hongkong"]),
                 enterprises: Some(vec![
@@ -1813,68 +2323,68 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "sino land",
                 ]),
                 misc: Some(vec!["legco"]),
-            }
-            .get_region_vec(),
+            },
             "hk",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "hm"),
+                automated: get_automated_keyphrases(region_map, "hm"),
+                acronyms: None,
                 names: Some(vec!["heard island", "mcdonald island"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "hm",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "hn"),
+                automated: get_automated_keyphrases(region_map, "hn"),
+                acronyms: None,
                 names: Some(vec!["hondura"]),
                 demonyms: Some(vec!["catrach"]),
                 enterprises: None,
                 misc: Some(vec!["liberty and refoundation"]),
-            }
-            .get_region_vec(),
+            },
             "hn",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "hr"),
+                automated: get_automated_keyphrases(region_map, "hr"),
+                acronyms: None,
                 names: Some(vec!["croatia"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["hdz"]),
-            }
-            .get_region_vec(),
+            },
             "hr",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ht"),
+                automated: get_automated_keyphrases(region_map, "ht"),
+                acronyms: None,
                 names: Some(vec!["haiti"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["phtk"]),
-            }
-            .get_region_vec(),
+            },
             "ht",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "hu"),
+                automated: get_automated_keyphrases(region_map, "hu"),
+                acronyms: None,
                 names: Some(vec!["hungar"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["fidesz", "orban"]),
-            }
-            .get_region_vec(),
+            },
             "hu",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "id"),
+                automated: get_automated_keyphrases(region_map, "id"),
+                acronyms: None,
                 names: Some(vec!["indonesia"]),
                 demonyms: None,
                 enterprises: Some(vec![
@@ -1886,13 +2396,13 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "astra international",
                 ]),
                 misc: Some(vec!["pdi-p", "golkar", "prosperous justice party"]),
-            }
-            .get_region_vec(),
+            },
             "id",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ie"),
+                automated: get_automated_keyphrases(region_map, "ie"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["irish"]),
                 enterprises: Some(vec![
@@ -1920,13 +2430,13 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "allegion",
                 ]),
                 misc: Some(vec!["oireachtas", "fianna fail", "fine gael", "sinn fein"]),
-            }
-            .get_region_vec(),
+            },
             "ie",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "il"),
+                automated: get_automated_keyphrases(region_map, "il"),
+                acronyms: Some(vec!["IDF"]),
                 names: Some(vec!["israel"]),
                 demonyms: None,
                 enterprises: Some(vec![
@@ -1954,24 +2464,24 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "kibbutz",
                     "shin bet",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "il",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "im"),
+                automated: get_automated_keyphrases(region_map, "im"),
+                acronyms: None,
                 names: Some(vec!["isle of man"]),
                 demonyms: Some(vec!["manx"]),
                 enterprises: None,
                 misc: Some(vec!["tynwald"]),
-            }
-            .get_region_vec(),
+            },
             "im",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "in"),
+                automated: get_automated_keyphrases(region_map, "in"),
+                acronyms: None,
                 names: Some(vec!["india", "hindustan"]),
                 demonyms: None,
                 enterprises: Some(vec![
@@ -2051,57 +2561,57 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "mankind pharma",
                 ]),
                 misc: Some(vec!["lok sabha", "rajya sabha", "bjp"]),
-            }
-            .get_region_vec(),
+            },
             "in",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "io"),
+                automated: get_automated_keyphrases(region_map, "io"),
+                acronyms: None,
                 names: Some(vec!["british indian ocean territory"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "io",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "iq"),
+                automated: get_automated_keyphrases(region_map, "iq"),
+                acronyms: None,
                 names: Some(vec!["iraq"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["takadum", "emtidad"]),
-            }
-            .get_region_vec(),
+            },
             "iq",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ir"),
+                automated: get_automated_keyphrases(region_map, "ir"),
+                acronyms: Some(vec!["IRGC"]),
                 names: Some(vec!["iran ", "iran'", "iran\"", "iran.", "iran,"]),
                 demonyms: Some(vec!["iranian"]),
                 enterprises: None,
                 misc: Some(vec!["guardian council", "khomeini"]),
-            }
-            .get_region_vec(),
+            },
             "ir",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "is"),
+                automated: get_automated_keyphrases(region_map, "is"),
+                acronyms: None,
                 names: Some(vec!["iceland"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["althing", "samfylkingin"]),
-            }
-            .get_region_vec(),
+            },
             "is",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "it"),
+                automated: get_automated_keyphrases(region_map, "it"),
+                acronyms: None,
                 names: Some(vec!["italy"]),
                 demonyms: Some(vec!["italian"]),
                 enterprises: Some(vec![
@@ -2126,13 +2636,13 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "finecobank",
                 ]),
                 misc: Some(vec!["lega", "pd-idp"]),
-            }
-            .get_region_vec(),
+            },
             "it",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "je"),
+                automated: get_automated_keyphrases(region_map, "je"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec![
                     "jerseyman",
@@ -2143,35 +2653,35 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                 ]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "je",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "jm"),
+                automated: get_automated_keyphrases(region_map, "jm"),
+                acronyms: None,
                 names: None, // Name comes from database.
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "jm",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "jo"),
+                automated: get_automated_keyphrases(region_map, "jo"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["jordanian"]),
                 enterprises: None,
                 misc: Some(vec!["islamic action front"]),
-            }
-            .get_region_vec(),
+            },
             "jo",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "jp"),
+                automated: get_automated_keyphrases(region_map, "jp"),
+                acronyms: None,
                 names: Some(vec!["japan", "nippon"]),
                 demonyms: None,
                 enterprises: Some(vec![
@@ -2266,90 +2776,90 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "\"k\" line",
                 ]),
                 misc: Some(vec!["komeito", "tokio"]),
-            }
-            .get_region_vec(),
+            },
             "jp",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ke"),
+                automated: get_automated_keyphrases(region_map, "ke"),
+                acronyms: None,
                 names: Some(vec!["kenya"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["azimio"]),
-            }
-            .get_region_vec(),
+            },
             "ke",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "kg"),
+                automated: get_automated_keyphrases(region_map, "kg"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["kyrgyz"]),
                 enterprises: None,
                 misc: Some(vec!["jogorku kenesh", "mekenchil", "eldik"]),
-            }
-            .get_region_vec(),
+            },
             "kg",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "kh"),
+                automated: get_automated_keyphrases(region_map, "kh"),
+                acronyms: None,
                 names: Some(vec!["cambodia"]),
                 demonyms: Some(vec!["khmer"]),
                 enterprises: None,
                 misc: Some(vec!["funcinpec"]),
-            }
-            .get_region_vec(),
+            },
             "kh",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ki"),
+                automated: get_automated_keyphrases(region_map, "ki"),
+                acronyms: None,
                 names: Some(vec!["kiribati"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "ki",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "km"),
+                automated: get_automated_keyphrases(region_map, "km"),
+                acronyms: None,
                 names: Some(vec!["comoros"]),
                 demonyms: Some(vec!["comorian"]),
                 enterprises: None,
                 misc: Some(vec!["orange party"]),
-            }
-            .get_region_vec(),
+            },
             "km",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "kn"),
+                automated: get_automated_keyphrases(region_map, "kn"),
+                acronyms: None,
                 names: Some(vec!["kitts", "nevis"]),
                 demonyms: Some(vec!["kittitian", "nevisian"]),
                 enterprises: None,
                 misc: Some(vec!["concerned citizens' movement"]),
-            }
-            .get_region_vec(),
+            },
             "kn",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "kp"),
+                automated: get_automated_keyphrases(region_map, "kp"),
+                acronyms: None,
                 names: Some(vec!["north korea"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["supreme people's assembly", "dprk"]),
-            }
-            .get_region_vec(),
+            },
             "kp",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "kr"),
+                automated: get_automated_keyphrases(region_map, "kr"),
+                acronyms: None,
                 names: Some(vec!["south korea"]),
                 demonyms: None,
                 enterprises: Some(vec![
@@ -2370,57 +2880,57 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "hana group",
                 ]),
                 misc: Some(vec!["people power party"]),
-            }
-            .get_region_vec(),
+            },
             "kr",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "kw"),
+                automated: get_automated_keyphrases(region_map, "kw"),
+                acronyms: None,
                 names: Some(vec!["kuwait"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "kw",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ky"),
+                automated: get_automated_keyphrases(region_map, "ky"),
+                acronyms: None,
                 names: Some(vec!["cayman"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "ky",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "kz"),
+                automated: get_automated_keyphrases(region_map, "kz"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["kazakh"]),
                 enterprises: None,
                 misc: Some(vec!["mazhilis", "amanat", "auyl"]),
-            }
-            .get_region_vec(),
+            },
             "kz",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "la"),
+                automated: get_automated_keyphrases(region_map, "la"),
+                acronyms: None,
                 names: Some(vec!["laos"]),
                 demonyms: Some(vec!["lao", "laotian"]), // Strings with length 3 or less are processed before substring checking.
                 enterprises: None,
                 misc: Some(vec!["lprp"]),
-            }
-            .get_region_vec(),
+            },
             "la",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "lb"),
+                automated: get_automated_keyphrases(region_map, "lb"),
+                acronyms: None,
                 names: Some(vec!["lebanon"]),
                 demonyms: Some(vec!["lebanese"]),
                 enterprises: None,
@@ -2431,112 +2941,112 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "march 14 alliance",
                     "march 8 alliance",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "lb",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "lc"),
+                automated: get_automated_keyphrases(region_map, "lc"),
+                acronyms: None,
                 names: Some(vec!["saint lucia"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "lc",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "li"),
+                automated: get_automated_keyphrases(region_map, "li"),
+                acronyms: None,
                 names: Some(vec!["liechtenstein"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "li",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "lk"),
+                automated: get_automated_keyphrases(region_map, "lk"),
+                acronyms: None,
                 names: Some(vec!["sri lanka"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["slpfa", "samagi jana balawegaya"]),
-            }
-            .get_region_vec(),
+            },
             "lk",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "lr"),
+                automated: get_automated_keyphrases(region_map, "lr"),
+                acronyms: None,
                 names: Some(vec!["liberia"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["coalition for democratic change"]),
-            }
-            .get_region_vec(),
+            },
             "lr",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ls"),
+                automated: get_automated_keyphrases(region_map, "ls"),
+                acronyms: None,
                 names: None, // Name comes from database.
                 demonyms: Some(vec!["mosotho", "basotho"]),
                 enterprises: None,
                 misc: Some(vec!["revolution for prosperity"]),
-            }
-            .get_region_vec(),
+            },
             "ls",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "lt"),
+                automated: get_automated_keyphrases(region_map, "lt"),
+                acronyms: None,
                 names: Some(vec!["lithuania"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["seimas", "homeland union", "lvzs"]),
-            }
-            .get_region_vec(),
+            },
             "lt",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "lu"),
+                automated: get_automated_keyphrases(region_map, "lu"),
+                acronyms: None,
                 names: None, // Name comes from database.
                 demonyms: None,
                 enterprises: Some(vec!["arcelormittal", "tenaris", "eurofins"]),
                 misc: Some(vec!["christian social people's party", "lsap"]),
-            }
-            .get_region_vec(),
+            },
             "lu",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "lv"),
+                automated: get_automated_keyphrases(region_map, "lv"),
+                acronyms: None,
                 names: Some(vec!["latvia"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["saeima", "zzs"]),
-            }
-            .get_region_vec(),
+            },
             "lv",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ly"),
+                automated: get_automated_keyphrases(region_map, "ly"),
+                acronyms: None,
                 names: None, // Name comes from database.
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["government of national"]),
-            }
-            .get_region_vec(),
+            },
             "ly",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ma"),
+                automated: get_automated_keyphrases(region_map, "ma"),
+                acronyms: None,
                 names: Some(vec!["morocc"]),
                 demonyms: None,
                 enterprises: None,
@@ -2546,222 +3056,222 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "authenticity and modernity party",
                     "usfp",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "ma",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "mc"),
+                automated: get_automated_keyphrases(region_map, "mc"),
+                acronyms: None,
                 names: None, // Name comes from database.
                 demonyms: Some(vec!["monegasque", "monacan"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "mc",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "md"),
+                automated: get_automated_keyphrases(region_map, "md"),
+                acronyms: None,
                 names: Some(vec!["moldova"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["party of action and solidarity", "psrm"]),
-            }
-            .get_region_vec(),
+            },
             "md",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "me"),
+                automated: get_automated_keyphrases(region_map, "me"),
+                acronyms: None,
                 names: Some(vec!["monteneg"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["pes!"]),
-            }
-            .get_region_vec(),
+            },
             "me",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "mf"),
+                automated: get_automated_keyphrases(region_map, "mf"),
+                acronyms: None,
                 names: Some(vec!["saint martin"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "mf",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "mg"),
+                automated: get_automated_keyphrases(region_map, "mg"),
+                acronyms: None,
                 names: Some(vec!["madagas"]),
                 demonyms: Some(vec!["malagas"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "mg",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "mh"),
+                automated: get_automated_keyphrases(region_map, "mh"),
+                acronyms: None,
                 names: Some(vec!["marshall island"]),
                 demonyms: Some(vec!["marshallese"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "mh",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "mk"),
+                automated: get_automated_keyphrases(region_map, "mk"),
+                acronyms: None,
                 names: Some(vec!["north macedonia"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["sobranie", "sdsm", "vmro-dpmne"]),
-            }
-            .get_region_vec(),
+            },
             "mk",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ml"),
+                automated: get_automated_keyphrases(region_map, "ml"),
+                acronyms: None,
                 names: Some(vec!["mali ", "mali'", "mali\"", "mali.", "mali,"]),
                 demonyms: Some(vec!["malian ", "malian'", "malian\"", "malian.", "malian,"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "ml",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "mm"),
+                automated: get_automated_keyphrases(region_map, "mm"),
+                acronyms: None,
                 names: Some(vec!["myanma"]),
                 demonyms: Some(vec!["burmese"]),
                 enterprises: None,
                 misc: Some(vec!["pyidaungsu hluttaw", "nld"]),
-            }
-            .get_region_vec(),
+            },
             "mm",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "mn"),
+                automated: get_automated_keyphrases(region_map, "mn"),
+                acronyms: None,
                 names: Some(vec!["mongol"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["state great khural"]),
-            }
-            .get_region_vec(),
+            },
             "mn",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "mo"),
+                automated: get_automated_keyphrases(region_map, "mo"),
+                acronyms: None,
                 names: Some(vec!["macau", "macao"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "mo",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "mp"),
+                automated: get_automated_keyphrases(region_map, "mp"),
+                acronyms: None,
                 names: Some(vec!["northern mariana island"]),
                 demonyms: Some(vec!["marianan", "chamorro"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "mp",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "mq"),
+                automated: get_automated_keyphrases(region_map, "mq"),
+                acronyms: None,
                 names: Some(vec!["martiniq"]),
                 demonyms: Some(vec!["martinic"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "mq",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "mr"),
+                automated: get_automated_keyphrases(region_map, "mr"),
+                acronyms: None,
                 names: Some(vec!["mauritania"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["tewassoul"]),
-            }
-            .get_region_vec(),
+            },
             "mr",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ms"),
+                automated: get_automated_keyphrases(region_map, "ms"),
+                acronyms: None,
                 names: Some(vec!["montserrat"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["movement for change and prosperity"]),
-            }
-            .get_region_vec(),
+            },
             "ms",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "mt"),
+                automated: get_automated_keyphrases(region_map, "mt"),
+                acronyms: None,
                 names: Some(vec!["malta"]),
                 demonyms: Some(vec!["maltese"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "mt",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "mu"),
+                automated: get_automated_keyphrases(region_map, "mu"),
+                acronyms: None,
                 names: Some(vec!["mauriti"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["mauricien"]),
-            }
-            .get_region_vec(),
+            },
             "mu",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "mv"),
+                automated: get_automated_keyphrases(region_map, "mv"),
+                acronyms: None,
                 names: Some(vec!["maldiv"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["people's majlis"]),
-            }
-            .get_region_vec(),
+            },
             "mv",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "mw"),
+                automated: get_automated_keyphrases(region_map, "mw"),
+                acronyms: None,
                 names: Some(vec!["malawi"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "mw",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "mx"),
+                automated: get_automated_keyphrases(region_map, "mx"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["mexican"]),
                 enterprises: Some(vec![
@@ -2778,13 +3288,13 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "aeroportuario del sureste",
                 ]),
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "mx",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "my"),
+                automated: get_automated_keyphrases(region_map, "my"),
+                acronyms: None,
                 names: Some(vec!["malaysia"]),
                 demonyms: None,
                 enterprises: Some(vec![
@@ -2798,90 +3308,90 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "celcomdigi",
                 ]),
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "my",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "mz"),
+                automated: get_automated_keyphrases(region_map, "mz"),
+                acronyms: None,
                 names: Some(vec!["mozambi"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["frelimo", "renamo"]),
-            }
-            .get_region_vec(),
+            },
             "mz",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "na"),
+                automated: get_automated_keyphrases(region_map, "na"),
+                acronyms: None,
                 names: Some(vec!["namibia"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["swapo"]),
-            }
-            .get_region_vec(),
+            },
             "na",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "nc"),
+                automated: get_automated_keyphrases(region_map, "nc"),
+                acronyms: None,
                 names: Some(vec!["new caledonia"]),
                 demonyms: Some(vec!["caledonian"]),
                 enterprises: None,
                 misc: Some(vec!["flnks", "l'eo"]),
-            }
-            .get_region_vec(),
+            },
             "nc",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ne"),
+                automated: get_automated_keyphrases(region_map, "ne"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["nigerien"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "ne",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "nf"),
+                automated: get_automated_keyphrases(region_map, "nf"),
+                acronyms: None,
                 names: Some(vec!["norfolk island"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "nf",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ng"),
+                automated: get_automated_keyphrases(region_map, "ng"),
+                acronyms: None,
                 names: Some(vec!["nigeria"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["all progressives congress"]),
-            }
-            .get_region_vec(),
+            },
             "ng",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ni"),
+                automated: get_automated_keyphrases(region_map, "ni"),
+                acronyms: None,
                 names: Some(vec!["nicaragua"]),
                 demonyms: Some(vec!["pinoler"]),
                 enterprises: None,
                 misc: Some(vec!["sandinista"]),
-            }
-            .get_region_vec(),
+            },
             "ni",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "nl"),
+                automated: get_automated_keyphrases(region_map, "nl"),
+                acronyms: None,
                 names: Some(vec!["netherlands", "nederland"]),
                 demonyms: Some(vec!["dutch"]),
                 enterprises: Some(vec![
@@ -2919,13 +3429,13 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "icc",
                     "international criminal court",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "nl",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "no"),
+                automated: get_automated_keyphrases(region_map, "no"),
+                acronyms: None,
                 names: Some(vec!["norway"]),
                 demonyms: Some(vec!["norwegian"]),
                 enterprises: Some(vec![
@@ -2938,112 +3448,112 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "norsk hydro",
                 ]),
                 misc: Some(vec!["storting"]),
-            }
-            .get_region_vec(),
+            },
             "no",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "np"),
+                automated: get_automated_keyphrases(region_map, "np"),
+                acronyms: None,
                 names: Some(vec!["nepal"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "np",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "nr"),
+                automated: get_automated_keyphrases(region_map, "nr"),
+                acronyms: None,
                 names: Some(vec!["nauru"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "nr",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "nu"),
+                automated: get_automated_keyphrases(region_map, "nu"),
+                acronyms: None,
                 names: Some(vec!["niue"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "nu",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "nz"),
+                automated: get_automated_keyphrases(region_map, "nz"),
+                acronyms: None,
                 names: Some(vec!["new zealand"]),
                 demonyms: Some(vec!["kiwi"]),
                 enterprises: Some(vec!["xero", "fisher & paykel"]),
                 misc: Some(vec!["parliament", "nzlp"]),
-            }
-            .get_region_vec(),
+            },
             "nz",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "om"),
+                automated: get_automated_keyphrases(region_map, "om"),
+                acronyms: None,
                 names: Some(vec!["oman"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "om",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "pa"),
+                automated: get_automated_keyphrases(region_map, "pa"),
+                acronyms: None,
                 names: Some(vec!["panama"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["molirena"]),
-            }
-            .get_region_vec(),
+            },
             "pa",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "pe"),
+                automated: get_automated_keyphrases(region_map, "pe"),
+                acronyms: None,
                 names: Some(vec!["peru"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["fujimoris"]),
-            }
-            .get_region_vec(),
+            },
             "pe",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "pf"),
+                automated: get_automated_keyphrases(region_map, "pf"),
+                acronyms: None,
                 names: Some(vec!["french polynesia"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["tavini", "tapura"]),
-            }
-            .get_region_vec(),
+            },
             "pf",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "pg"),
+                automated: get_automated_keyphrases(region_map, "pg"),
+                acronyms: None,
                 names: Some(vec!["papua new guinea"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["pangu pati"]),
-            }
-            .get_region_vec(),
+            },
             "pg",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ph"),
+                automated: get_automated_keyphrases(region_map, "ph"),
+                acronyms: None,
                 names: Some(vec!["philippine"]),
                 demonyms: Some(vec!["filipin", "pinoy"]),
                 enterprises: Some(vec![
@@ -3056,24 +3566,24 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "ayala",
                 ]),
                 misc: Some(vec!["uniteam alliance", "tropa"]),
-            }
-            .get_region_vec(),
+            },
             "ph",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "pk"),
+                automated: get_automated_keyphrases(region_map, "pk"),
+                acronyms: None,
                 names: Some(vec!["pakistan"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["pml-n", "ittehad council"]),
-            }
-            .get_region_vec(),
+            },
             "pk",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "pl"),
+                automated: get_automated_keyphrases(region_map, "pl"),
+                acronyms: None,
                 names: Some(vec!["poland", "polsk"]),
                 demonyms: Some(vec!["polish"]),
                 enterprises: Some(vec![
@@ -3087,51 +3597,50 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "allegro.eu",
                 ]),
                 misc: Some(vec!["sejm"]),
-            }
-            .get_region_vec(),
+            },
             "pl",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "pm"),
+                automated: get_automated_keyphrases(region_map, "pm"),
+                acronyms: None,
                 names: Some(vec!["saint pierre", "miquelon"]),
                 demonyms: Some(vec!["saint-pierrais", "miquelonnais", "pierrian"]),
                 enterprises: None,
                 misc: Some(vec!["archipelago tomorrow"]),
-            }
-            .get_region_vec(),
+            },
             "pm",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "pn"),
+                automated: get_automated_keyphrases(region_map, "pn"),
+                acronyms: None,
                 names: Some(vec!["pitcairn"]),
                 demonyms: Some(vec!["pitkern"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "pn",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "pr"),
+                automated: get_automated_keyphrases(region_map, "pr"),
+                acronyms: None,
                 names: Some(vec!["puerto ric"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "pr",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ps"),
+                automated: get_automated_keyphrases(region_map, "ps"),
+                acronyms: Some(vec!["PLO"]),
                 names: Some(vec!["palestin"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec![
-                    "plo",
                     "hamas",
                     "fatah",
                     "gaza",
@@ -3140,90 +3649,90 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "khan younis",
                     "khan yunus",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "ps",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "pt"),
+                automated: get_automated_keyphrases(region_map, "pt"),
+                acronyms: None,
                 names: Some(vec!["portugal"]),
                 demonyms: Some(vec!["portuguese"]),
                 enterprises: Some(vec!["edp group", "galp energ", "jeronimo martins"]),
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "pt",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "pw"),
+                automated: get_automated_keyphrases(region_map, "pw"),
+                acronyms: None,
                 names: Some(vec!["palau"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "pw",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "py"),
+                automated: get_automated_keyphrases(region_map, "py"),
+                acronyms: None,
                 names: Some(vec!["paraguay"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "py",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "qa"),
+                automated: get_automated_keyphrases(region_map, "qa"),
+                acronyms: None,
                 names: Some(vec!["qatar"]),
                 demonyms: None,
                 enterprises: Some(vec!["qnb inc"]),
                 misc: Some(vec!["house of thani"]),
-            }
-            .get_region_vec(),
+            },
             "qa",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "re"),
+                automated: get_automated_keyphrases(region_map, "re"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["reunionese"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "re",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ro"),
+                automated: get_automated_keyphrases(region_map, "ro"),
+                acronyms: None,
                 names: Some(vec!["romania"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "ro",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "rs"),
+                automated: get_automated_keyphrases(region_map, "rs"),
+                acronyms: None,
                 names: Some(vec!["serbia"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["av-zms", "sps-zs"]),
-            }
-            .get_region_vec(),
+            },
             "rs",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ru"),
+                automated: get_automated_keyphrases(region_map, "ru"),
+                acronyms: None,
                 names: Some(vec!["russia"]),
                 demonyms: None,
                 enterprises: Some(vec![
@@ -3240,24 +3749,24 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "surgutneftegas",
                 ]),
                 misc: Some(vec!["state duma", "ldpr", "putin"]),
-            }
-            .get_region_vec(),
+            },
             "ru",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "rw"),
+                automated: get_automated_keyphrases(region_map, "rw"),
+                acronyms: None,
                 names: Some(vec!["rwand"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "rw",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "sa"),
+                automated: get_automated_keyphrases(region_map, "sa"),
+                acronyms: Some(vec!["KSA"]),
                 names: None,
                 demonyms: Some(vec!["saudi"]),
                 enterprises: Some(vec![
@@ -3277,46 +3786,46 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "mobily",
                 ]),
                 misc: Some(vec!["mount arafat"]),
-            }
-            .get_region_vec(),
+            },
             "sa",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "sb"),
+                automated: get_automated_keyphrases(region_map, "sb"),
+                acronyms: None,
                 names: Some(vec!["solomon island"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["kadere party"]),
-            }
-            .get_region_vec(),
+            },
             "sb",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "sc"),
+                automated: get_automated_keyphrases(region_map, "sc"),
+                acronyms: None,
                 names: Some(vec!["seychell"]),
                 demonyms: Some(vec!["seselwa"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "sc",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "sd"),
+                automated: get_automated_keyphrases(region_map, "sd"),
+                acronyms: None,
                 names: None,
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "sd",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "se"),
+                automated: get_automated_keyphrases(region_map, "se"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["swedish", "swede"]),
                 enterprises: Some(vec![
@@ -3348,13 +3857,13 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "telia",
                 ]),
                 misc: Some(vec!["riksdag"]),
-            }
-            .get_region_vec(),
+            },
             "se",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "sg"),
+                automated: get_automated_keyphrases(region_map, "sg"),
+                acronyms: None,
                 names: Some(vec!["singapore"]),
                 demonyms: None,
                 enterprises: Some(vec![
@@ -3369,200 +3878,200 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "capitaland",
                 ]),
                 misc: Some(vec!["people's action party"]),
-            }
-            .get_region_vec(),
+            },
             "sg",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "sh"),
+                automated: get_automated_keyphrases(region_map, "sh"),
+                acronyms: None,
                 names: Some(vec!["saint helen"]),
                 demonyms: Some(vec!["helenian"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "sh",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "si"),
+                automated: get_automated_keyphrases(region_map, "si"),
+                acronyms: None,
                 names: Some(vec!["sloven"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "si",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "sj"),
+                automated: get_automated_keyphrases(region_map, "sj"),
+                acronyms: None,
                 names: Some(vec!["svalbard", "jan mayen"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "sj",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "sk"),
+                automated: get_automated_keyphrases(region_map, "sk"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["slovak"]),
                 enterprises: None,
                 misc: Some(vec!["smer-sd", "hlas-sd"]),
-            }
-            .get_region_vec(),
+            },
             "sk",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "sl"),
+                automated: get_automated_keyphrases(region_map, "sl"),
+                acronyms: None,
                 names: Some(vec!["sierra leone"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "sl",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "sm"),
+                automated: get_automated_keyphrases(region_map, "sm"),
+                acronyms: None,
                 names: Some(vec!["san marino"]),
                 demonyms: Some(vec!["sammarinese"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "sm",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "sn"),
+                automated: get_automated_keyphrases(region_map, "sn"),
+                acronyms: None,
                 names: Some(vec!["senegal"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "sn",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "so"),
+                automated: get_automated_keyphrases(region_map, "so"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["somali"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "so",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "sr"),
+                automated: get_automated_keyphrases(region_map, "sr"),
+                acronyms: None,
                 names: Some(vec!["suriname"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "sr",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ss"),
+                automated: get_automated_keyphrases(region_map, "ss"),
+                acronyms: None,
                 names: Some(vec!["south sudan"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["splm-in-opposition"]),
-            }
-            .get_region_vec(),
+            },
             "ss",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "st"),
+                automated: get_automated_keyphrases(region_map, "st"),
+                acronyms: None,
                 names: Some(vec!["sao tome", "principe"]),
                 demonyms: Some(vec!["santomean"]),
                 enterprises: None,
                 misc: Some(vec!["mlstp"]),
-            }
-            .get_region_vec(),
+            },
             "st",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "sv"),
+                automated: get_automated_keyphrases(region_map, "sv"),
+                acronyms: None,
                 names: Some(vec!["el salvador"]),
                 demonyms: Some(vec!["salvadoran"]),
                 enterprises: None,
                 misc: Some(vec!["nuevas ideas"]),
-            }
-            .get_region_vec(),
+            },
             "sv",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "sx"),
+                automated: get_automated_keyphrases(region_map, "sx"),
+                acronyms: None,
                 names: Some(vec!["maarten"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "sx",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "sy"),
+                automated: get_automated_keyphrases(region_map, "sy"),
+                acronyms: None,
                 names: Some(vec!["syria"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "sy",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "sz"),
+                automated: get_automated_keyphrases(region_map, "sz"),
+                acronyms: None,
                 names: Some(vec!["eswatini"]),
                 demonyms: Some(vec!["swazi"]),
                 enterprises: None,
                 misc: Some(vec!["tinkhundla"]),
-            }
-            .get_region_vec(),
+            },
             "sz",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "tc"),
+                automated: get_automated_keyphrases(region_map, "tc"),
+                acronyms: None,
                 names: Some(vec!["turks and c", "caicos"]),
                 demonyms: Some(vec!["turks islander"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "tc",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "td"),
+                automated: get_automated_keyphrases(region_map, "td"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["chadian"]),
                 enterprises: None,
                 misc: Some(vec!["national transitional council"]),
-            }
-            .get_region_vec(),
+            },
             "td",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "tf"),
+                automated: get_automated_keyphrases(region_map, "tf"),
+                acronyms: None,
                 names: Some(vec![
                     "french southern territories",
                     "adelie land",
@@ -3574,24 +4083,24 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                 demonyms: Some(vec!["kerguelenois"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "tf",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "tg"),
+                automated: get_automated_keyphrases(region_map, "tg"),
+                acronyms: None,
                 names: Some(vec!["togo"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["union of forces for change"]),
-            }
-            .get_region_vec(),
+            },
             "tg",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "th"),
+                automated: get_automated_keyphrases(region_map, "th"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["thai"]),
                 enterprises: Some(vec![
@@ -3604,57 +4113,57 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "siam bank",
                 ]),
                 misc: Some(vec!["bhumjaithai", "palang pracharath"]),
-            }
-            .get_region_vec(),
+            },
             "th",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "tj"),
+                automated: get_automated_keyphrases(region_map, "tj"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["tajik"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "tj",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "tk"),
+                automated: get_automated_keyphrases(region_map, "tk"),
+                acronyms: None,
                 names: Some(vec!["tokelau"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "tk",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "tl"),
+                automated: get_automated_keyphrases(region_map, "tl"),
+                acronyms: None,
                 names: Some(vec!["timor-leste", "east timor"]),
                 demonyms: Some(vec!["timorese"]),
                 enterprises: None,
                 misc: Some(vec!["national parliament", "cnrt", "fretilin"]),
-            }
-            .get_region_vec(),
+            },
             "tl",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "tm"),
+                automated: get_automated_keyphrases(region_map, "tm"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["turkmen"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "tm",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "tn"),
+                automated: get_automated_keyphrases(region_map, "tn"),
+                acronyms: None,
                 names: Some(vec!["tunisia"]),
                 demonyms: None,
                 enterprises: None,
@@ -3662,59 +4171,59 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "assembly of the representatives of the people",
                     "25th of july movement",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "tn",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "to"),
+                automated: get_automated_keyphrases(region_map, "to"),
+                acronyms: None,
                 names: Some(vec!["tonga"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "to",
         ),
         (
             RegionKeyphrases {
                 // I did not add "Ford Otosan", as I value the 'Ford' keyphrase more for the United States region.
-                automated: get_automated_keyphrases(&region_map, "tr"),
+                automated: get_automated_keyphrases(region_map, "tr"),
+                acronyms: None,
                 names: Some(vec!["turkey", "turkiye"]),
                 demonyms: Some(vec!["turkish"]),
                 enterprises: Some(vec!["qnb finansbank", "koc", "garantibank", "akbank"]),
                 misc: Some(vec!["grand national assembly"]),
-            }
-            .get_region_vec(),
+            },
             "tr",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "tt"),
+                automated: get_automated_keyphrases(region_map, "tt"),
+                acronyms: None,
                 names: Some(vec!["tobago"]),
                 demonyms: Some(vec!["trini", "trinbagonian"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "tt",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "tv"),
+                automated: get_automated_keyphrases(region_map, "tv"),
+                acronyms: None,
                 names: Some(vec!["tuvalu"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "tv",
         ),
         (
             RegionKeyphrases {
                 // I am not including "China Steel", as I value the 'China" keyphrase more for the China region.
-                automated: get_automated_keyphrases(&region_map, "tw"),
+                automated: get_automated_keyphrases(region_map, "tw"),
+                acronyms: None,
                 names: Some(vec!["taiwan"]),
                 demonyms: None,
                 enterprises: Some(vec![
@@ -3751,46 +4260,46 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "guomindang",
                     "formosa",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "tw",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "tz"),
+                automated: get_automated_keyphrases(region_map, "tz"),
+                acronyms: None,
                 names: Some(vec!["tanzania"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["chama cha mapinduzi"]),
-            }
-            .get_region_vec(),
+            },
             "tz",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ua"),
+                automated: get_automated_keyphrases(region_map, "ua"),
+                acronyms: None,
                 names: Some(vec!["ukrain"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["verkhovna rada", "zelensky", "azov"]),
-            }
-            .get_region_vec(),
+            },
             "ua",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ug"),
+                automated: get_automated_keyphrases(region_map, "ug"),
+                acronyms: None,
                 names: Some(vec!["uganda"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "ug",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "um"),
+                automated: get_automated_keyphrases(region_map, "um"),
+                acronyms: None,
                 names: Some(vec![
                     "united states minor outlying islands",
                     "baker island",
@@ -3806,13 +4315,13 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "um",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "us"),
+                automated: get_automated_keyphrases(region_map, "us"),
+                acronyms: Some(vec!["US"]),
                 names: Some(vec!["usa", "u.s.a."]),
                 demonyms: None,
                 enterprises: Some(vec![
@@ -4458,79 +4967,79 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "ifc",
                     "leahy",
                 ]),
-            }
-            .get_region_vec(),
+            },
             "us",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "uy"),
+                automated: get_automated_keyphrases(region_map, "uy"),
+                acronyms: None,
                 names: Some(vec!["uruguay"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "uy",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "uz"),
+                automated: get_automated_keyphrases(region_map, "uz"),
+                acronyms: None,
                 names: Some(vec!["uzbekistan"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["justice social democratic party"]),
-            }
-            .get_region_vec(),
+            },
             "uz",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "va"),
+                automated: get_automated_keyphrases(region_map, "va"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["vatican"]),
                 enterprises: None,
                 misc: Some(vec!["college of cardinals", "pope"]),
-            }
-            .get_region_vec(),
+            },
             "va",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "vc"),
+                automated: get_automated_keyphrases(region_map, "vc"),
+                acronyms: None,
                 names: Some(vec!["saint vincent", "grenadines"]),
                 demonyms: Some(vec!["vincentian", "grenadian", "vincy"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "vc",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ve"),
+                automated: get_automated_keyphrases(region_map, "ve"),
+                acronyms: None,
                 names: Some(vec!["venezuela"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["psuv"]),
-            }
-            .get_region_vec(),
+            },
             "ve",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "vg"),
+                automated: get_automated_keyphrases(region_map, "vg"),
+                acronyms: None,
                 names: Some(vec!["british virgin islands"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "vg",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "vi"),
+                automated: get_automated_keyphrases(region_map, "vi"),
+                acronyms: None,
                 names: Some(vec![
                     "united states virgin islands",
                     "us virgin islands",
@@ -4539,90 +5048,90 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "vi",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "vn"),
+                automated: get_automated_keyphrases(region_map, "vn"),
+                acronyms: None,
                 names: None,
                 demonyms: Some(vec!["viet"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "vn",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "vu"),
+                automated: get_automated_keyphrases(region_map, "vu"),
+                acronyms: None,
                 names: Some(vec!["vanua"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "vu",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "wf"),
+                automated: get_automated_keyphrases(region_map, "wf"),
+                acronyms: None,
                 names: Some(vec!["wallis", "futuna"]),
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "wf",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ws"),
+                automated: get_automated_keyphrases(region_map, "ws"),
+                acronyms: None,
                 names: None,
                 demonyms: None,
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "ws",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "ye"),
+                automated: get_automated_keyphrases(region_map, "ye"),
+                acronyms: None,
                 names: Some(vec!["yemen"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["houthi"]),
-            }
-            .get_region_vec(),
+            },
             "ye",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "yt"),
+                automated: get_automated_keyphrases(region_map, "yt"),
+                acronyms: None,
                 names: Some(vec!["mayotte"]),
                 demonyms: Some(vec!["mahoran", "mahorais"]),
                 enterprises: None,
                 misc: None,
-            }
-            .get_region_vec(),
+            },
             "yt",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "xk"),
+                automated: get_automated_keyphrases(region_map, "xk"),
+                acronyms: None,
                 names: Some(vec!["kosov"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["vetevendosje", "guxo"]),
-            }
-            .get_region_vec(),
+            },
             "xk",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "za"),
+                automated: get_automated_keyphrases(region_map, "za"),
+                acronyms: None,
                 names: Some(vec!["south africa"]),
                 demonyms: None,
                 enterprises: Some(vec![
@@ -4635,35 +5144,183 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
                     "vodacom",
                 ]),
                 misc: Some(vec!["african national congress"]),
-            }
-            .get_region_vec(),
+            },
             "za",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "zm"),
+                automated: get_automated_keyphrases(region_map, "zm"),
+                acronyms: None,
                 names: Some(vec!["zambia"]),
                 demonyms: None,
                 enterprises: None,
                 misc: Some(vec!["upnd"]),
-            }
-            .get_region_vec(),
+            },
             "zm",
         ),
         (
             RegionKeyphrases {
-                automated: get_automated_keyphrases(&region_map, "zw"),
+                automated: get_automated_keyphrases(region_map, "zw"),
+                acronyms: None,
                 names: Some(vec!["zimbabwe"]),
                 demonyms: Some(vec!["zimbo"]),
                 enterprises: None,
                 misc: Some(vec!["zanu-pf", "citizens coalition for change"]),
-            }
-            .get_region_vec(),
+            },
             "zw",
         ),
-    ];
+        // Supranational blocs and organizations. See get_bloc_members.
+        (
+            RegionKeyphrases {
+                automated: get_automated_keyphrases(region_map, "african_union"),
+                acronyms: None,
+                names: Some(vec!["african union"]),
+                demonyms: None,
+                enterprises: None,
+                misc: Some(vec!["atmis", "amisom", "pan-african parliament"]),
+            },
+            "african_union",
+        ),
+        (
+            RegionKeyphrases {
+                automated: get_automated_keyphrases(region_map, "asean"),
+                acronyms: None,
+                names: Some(vec![" asean", "association of southeast asian nations"]),
+                demonyms: None,
+                enterprises: None,
+                misc: None,
+            },
+            "asean",
+        ),
+        (
+            RegionKeyphrases {
+                automated: get_automated_keyphrases(region_map, "eu"),
+                acronyms: Some(vec!["EU"]),
+                names: Some(vec!["european union"]),
+                demonyms: None,
+                enterprises: None,
+                misc: Some(vec![
+                    "european commission",
+                    "european parliament",
+                    "european council",
+                    "eurozone",
+                    "european central bank",
+                    "schengen area",
+                ]),
+            },
+            "eu",
+        ),
+        (
+            RegionKeyphrases {
+                automated: get_automated_keyphrases(region_map, "nato"),
+                acronyms: None,
+                names: Some(vec![" nato", "north atlantic treaty organization"]),
+                demonyms: None,
+                enterprises: None,
+                misc: Some(vec!["north atlantic council", "supreme allied commander"]),
+            },
+            "nato",
+        ),
+        (
+            RegionKeyphrases {
+                automated: get_automated_keyphrases(region_map, "opec"),
+                acronyms: None,
+                names: Some(vec![
+                    " opec",
+                    "organization of the petroleum exporting countries",
+                ]),
+                demonyms: None,
+                enterprises: None,
+                misc: None,
+            },
+            "opec",
+        ),
+        (
+            RegionKeyphrases {
+                automated: get_automated_keyphrases(region_map, "un"),
+                acronyms: Some(vec!["UN"]),
+                names: Some(vec!["united nations"]),
+                demonyms: None,
+                enterprises: None,
+                misc: Some(vec![
+                    "un security council",
+                    "un general assembly",
+                    "un secretary-general",
+                    "unicef",
+                    "unhcr",
+                    "unrwa",
+                    "unesco",
+                    "world food programme",
+                ]),
+            },
+            "un",
+        ),
+    ]
+}
 
-    let blacklist: HashSet<&'static str> = vec![
+// Member countries of the blocs in get_region_keyphrases, as of 2025.
+pub fn get_bloc_members() -> Vec<(&'static str, Vec<&'static str>)> {
+    vec![
+        (
+            "african_union",
+            vec![
+                "ao", "bf", "bi", "bj", "bw", "cd", "cf", "cg", "ci", "cm", "cv", "dj", "dz", "eg",
+                "eh", "er", "et", "ga", "gh", "gm", "gn", "gq", "gw", "ke", "km", "lr", "ls", "ly",
+                "ma", "mg", "ml", "mr", "mu", "mw", "mz", "na", "ne", "ng", "rw", "sc", "sd", "sl",
+                "sn", "so", "ss", "st", "sz", "td", "tg", "tn", "tz", "ug", "za", "zm", "zw",
+            ],
+        ),
+        (
+            "asean",
+            vec![
+                "bn", "id", "kh", "la", "mm", "my", "ph", "sg", "th", "tl", "vn",
+            ],
+        ),
+        (
+            "eu",
+            vec![
+                "at", "be", "bg", "cy", "cz", "de", "dk", "ee", "es", "fi", "fr", "gr", "hr", "hu",
+                "ie", "it", "lt", "lu", "lv", "mt", "nl", "pl", "pt", "ro", "se", "si", "sk",
+            ],
+        ),
+        (
+            "nato",
+            vec![
+                "al", "be", "bg", "ca", "cz", "de", "dk", "ee", "es", "fi", "fr", "gb", "gr", "hr",
+                "hu", "is", "it", "lt", "lu", "lv", "me", "mk", "nl", "no", "pl", "pt", "ro", "se",
+                "si", "sk", "tr", "us",
+            ],
+        ),
+        (
+            "opec",
+            vec![
+                "ae", "cg", "dz", "ga", "gq", "iq", "ir", "kw", "ly", "ng", "sa", "ve",
+            ],
+        ),
+        (
+            "un",
+            vec![
+                "ad", "ae", "af", "ag", "al", "am", "ao", "ar", "at", "au", "az", "ba", "bb", "bd",
+                "be", "bf", "bg", "bh", "bi", "bj", "bn", "bo", "br", "bs", "bt", "bw", "by", "bz",
+                "ca", "cd", "cf", "cg", "ch", "ci", "cl", "cm", "cn", "co", "cr", "cu", "cv", "cy",
+                "cz", "de", "dj", "dk", "dm", "do", "dz", "ec", "ee", "eg", "er", "es", "et", "fi",
+                "fj", "fm", "fr", "ga", "gb", "gd", "ge", "gh", "gm", "gn", "gq", "gr", "gt", "gw",
+                "gy", "hn", "hr", "ht", "hu", "id", "ie", "il", "in", "iq", "ir", "is", "it", "jm",
+                "jo", "jp", "ke", "kg", "kh", "ki", "km", "kn", "kp", "kr", "kw", "kz", "la", "lb",
+                "lc", "li", "lk", "lr", "ls", "lt", "lu", "lv", "ly", "ma", "mc", "md", "me", "mg",
+                "mh", "mk", "ml", "mm", "mn", "mr", "mt", "mu", "mv", "mw", "mx", "my", "mz", "na",
+                "ne", "ng", "ni", "nl", "no", "np", "nr", "nz", "om", "pa", "pe", "pg", "ph", "pk",
+                "pl", "pt", "pw", "py", "qa", "ro", "rs", "ru", "rw", "sa", "sb", "sc", "sd", "se",
+                "sg", "si", "sk", "sl", "sm", "sn", "so", "sr", "ss", "st", "sv", "sy", "sz", "td",
+                "tg", "th", "tj", "tl", "tm", "tn", "to", "tr", "tt", "tv", "tz", "ua", "ug", "us",
+                "uy", "uz", "vc", "ve", "vn", "vu", "ws", "ye", "za", "zm", "zw",
+            ],
+        ),
+    ]
+}
+
+fn get_blacklist() -> Vec<&'static str> {
+    vec![
         "north east",
         "north west",
         "south east",
@@ -4737,11 +5394,7 @@ pub static KEYPHRASE_REGION_MAP: Lazy<Vec<(Vec<&'static str>, &'static str)>> =
         "st. john's",
         "timis",
     ]
-    .into_par_iter()
-    .collect();
-
-    remove_ambiguities(map, blacklist)
-});
+}
 
 #[allow(dead_code)]
 pub async fn show_region_map() -> Result<()> {
@@ -4762,3 +5415,29 @@ pub async fn show_region_map() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removes_keyphrases_containing_shorter_ones() {
+        let keyphrases = RegionKeyphrases {
+            automated: Some(vec![
+                ("new york".to_string(), "place".to_string()),
+                ("york".to_string(), "place".to_string()),
+                ("yorkshire".to_string(), "place".to_string()),
+            ]),
+            acronyms: None,
+            names: Some(vec!["york", "leeds"]),
+            demonyms: None,
+            enterprises: None,
+            misc: None,
+        };
+        let mut region_vec = keyphrases.get_region_vec();
+        region_vec.sort_unstable();
+
+        // The manual "york" keeps its category over the automated duplicate.
+        assert_eq!(region_vec, vec![("leeds", "name"), ("york", "name")]);
+    }
+}
@@ -0,0 +1,185 @@
+use super::{
+    forbes400::get_forbes_billionaires,
+    wikidata::{get_sparql_endpoint, get_wikidata_billionaires},
+};
+use crate::prelude::*;
+use crate::service::var_service::{get_float_var, get_optional_var};
+use reqwest::Client;
+use serde_json::{from_str, json, to_string_pretty, Value};
+use std::{
+    fs::{read_to_string, write},
+    path::Path,
+};
+
+pub struct Billionaire {
+    pub id: String,
+    pub name: String,
+    pub region_code: String,
+    pub net_worth: f64, // Millions of USD
+    pub companies: Vec<String>,
+}
+
+// Names containing an excluded string are skipped, since they would also match another keyphrase (a billionaire or
+// minister named Jordan is not news about Jordan). Stripped strings are removed from names, such as " & family". The
+// rules apply to billionaires and office holders alike.
+pub struct NameRules {
+    excluded: Vec<String>,
+    stripped: Vec<String>,
+}
+
+impl Default for NameRules {
+    fn default() -> Self {
+        NameRules {
+            excluded: vec![
+                "chad".to_string(),
+                "denmark".to_string(),
+                "israel".to_string(),
+                "jordan".to_string(),
+            ],
+            stripped: vec![" & family".to_string()],
+        }
+    }
+}
+
+impl NameRules {
+    // The name without its stripped strings, or None when it contains an excluded string.
+    pub fn apply(&self, name: &str) -> Option<String> {
+        let mut name = name.to_string();
+        for stripped in &self.stripped {
+            name = name.replace(stripped, "");
+        }
+        let lowercase_name = name.to_lowercase();
+        match self
+            .excluded
+            .iter()
+            .any(|excluded| lowercase_name.contains(excluded))
+        {
+            true => None,
+            false => Some(name),
+        }
+    }
+}
+
+// Billionaires worth at least BILLIONAIRE_MIN_NET_WORTH, in millions of USD. They come from BILLIONAIRES_SNAPSHOT_PATH
+// when set, and otherwise from Forbes400 (or FORBES_PATH), then Wikidata, then the last snapshot saved in the volume.
+pub async fn get_billionaires(
+    client: &Client,
+    docker_volume: &str,
+    forbes_path: Option<&str>,
+) -> Result<Vec<Billionaire>> {
+    let min_net_worth = get_float_var("BILLIONAIRE_MIN_NET_WORTH", 9900.0).await?;
+    let snapshot_path = format!("{}/billionaires.json", docker_volume);
+    let billionaires = match get_optional_var("BILLIONAIRES_SNAPSHOT_PATH").await? {
+        Some(snapshot_path) => load_snapshot(&snapshot_path)?,
+        None => {
+            let mut billionaires = get_forbes_billionaires(client, forbes_path)
+                .await
+                .unwrap_or_else(|err| {
+                    tracing::error!("Failed to get billionaires from Forbes400: {}", err);
+                    Vec::new()
+                });
+            if billionaires.is_empty() {
+                tracing::info!("No billionaires from Forbes400. Falling back to Wikidata.");
+                billionaires =
+                    get_wikidata_billionaires(client, &get_sparql_endpoint().await?, min_net_worth)
+                        .await
+                        .unwrap_or_else(|err| {
+                            tracing::error!("Failed to get billionaires from Wikidata: {}", err);
+                            Vec::new()
+                        });
+            }
+            match billionaires.is_empty() {
+                true if Path::new(&snapshot_path).exists() => {
+                    tracing::error!("No billionaires retrieved. Using {snapshot_path}.");
+                    load_snapshot(&snapshot_path)?
+                }
+                true => billionaires,
+                false => {
+                    save_snapshot(&snapshot_path, &billionaires)?;
+                    billionaires
+                }
+            }
+        }
+    };
+
+    let rules = get_name_rules().await?;
+    Ok(billionaires
+        .into_iter()
+        .filter(|billionaire| billionaire.net_worth >= min_net_worth)
+        .filter_map(|mut billionaire| {
+            billionaire.name = rules.apply(&billionaire.name)?;
+            Some(billionaire)
+        })
+        .collect())
+}
+
+// Name rules from NAME_RULES_PATH, or the default ones.
+pub async fn get_name_rules() -> Result<NameRules> {
+    load_name_rules(get_optional_var("NAME_RULES_PATH").await?.as_deref())
+}
+
+fn load_name_rules(rules_path: Option<&str>) -> Result<NameRules> {
+    let Some(rules_path) = rules_path else {
+        return Ok(NameRules::default());
+    };
+
+    let json: Value = from_str(&read_to_string(rules_path)?)?;
+    let mut rules = NameRules::default();
+    if let Some(excluded) = json["excluded"].as_array() {
+        rules.excluded = get_strings(excluded)
+            .into_iter()
+            .map(|excluded| excluded.to_lowercase())
+            .collect();
+    }
+    if let Some(stripped) = json["stripped"].as_array() {
+        rules.stripped = get_strings(stripped);
+    }
+
+    Ok(rules)
+}
+
+fn load_snapshot(snapshot_path: &str) -> Result<Vec<Billionaire>> {
+    let json: Value = from_str(&read_to_string(snapshot_path)?)?;
+    Ok(json
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|billionaire| {
+            Some(Billionaire {
+                id: billionaire["id"].as_str()?.to_string(),
+                name: billionaire["name"].as_str()?.to_string(),
+                region_code: billionaire["region_code"].as_str()?.to_lowercase(),
+                net_worth: billionaire["net_worth"].as_f64()?,
+                companies: billionaire["companies"]
+                    .as_array()
+                    .map(|companies| get_strings(companies))
+                    .unwrap_or_default(),
+            })
+        })
+        .collect())
+}
+
+fn save_snapshot(snapshot_path: &str, billionaires: &[Billionaire]) -> Result<()> {
+    let snapshot: Vec<Value> = billionaires
+        .iter()
+        .map(|billionaire| {
+            json!({
+                "id": billionaire.id,
+                "name": billionaire.name,
+                "region_code": billionaire.region_code,
+                "net_worth": billionaire.net_worth,
+                "companies": billionaire.companies,
+            })
+        })
+        .collect();
+    write(snapshot_path, to_string_pretty(&snapshot)?)?;
+
+    Ok(())
+}
+
+fn get_strings(values: &[Value]) -> Vec<String> {
+    values
+        .iter()
+        .filter_map(|value| value.as_str().map(|value| value.to_string()))
+        .collect()
+}
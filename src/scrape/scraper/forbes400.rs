@@ -1,19 +1,29 @@
+use super::billionaires::Billionaire;
 use crate::prelude::*;
-use crate::scrape::util::get_iso_from_name;
+use crate::scrape::iso3166::get_code_from_name;
 use reqwest::Client;
-use serde_json::Value;
-use std::collections::HashMap;
+use serde_json::{from_str, Value};
+use std::fs::read_to_string;
 
-pub async fn get_largest_billionaires_map(client: &Client) -> Result<HashMap<String, Vec<String>>> {
-    let mut billionaires: HashMap<String, Vec<String>> = HashMap::new();
-    let url = "https://forbes400.onrender.com/api/forbes400/getAllBillionaires".to_string();
-    let response = client.get(&url).send().await?;
-    if !response.status().is_success() {
-        tracing::debug!("Non-success response from Forbes400: {}", response.status());
-        return Ok(billionaires);
-    }
+// A local snapshot of the getAllBillionaires response can be given in place of the Forbes400 API.
+pub async fn get_forbes_billionaires(
+    client: &Client,
+    snapshot_path: Option<&str>,
+) -> Result<Vec<Billionaire>> {
+    let mut billionaires = Vec::new();
+    let json: Value = match snapshot_path {
+        Some(snapshot_path) => from_str(&read_to_string(snapshot_path)?)?,
+        None => {
+            let url = "https://forbes400.onrender.com/api/forbes400/getAllBillionaires".to_string();
+            let response = client.get(&url).send().await?;
+            if !response.status().is_success() {
+                tracing::debug!("Non-success response from Forbes400: {}", response.status());
+                return Ok(billionaires);
+            }
 
-    let json: Value = response.json().await?;
+            response.json().await?
+        }
+    };
     let data = match json.as_array() {
         Some(data) => data,
         None => return Ok(billionaires),
@@ -24,12 +34,8 @@ pub async fn get_largest_billionaires_map(client: &Client) -> Result<HashMap<Str
             continue;
         };
 
-        if final_worth < 9900.0 {
-            continue;
-        }
-
         let citizenship = match billionaire["countryOfCitizenship"].as_str() {
-            Some(citizenship) => match get_iso_from_name(citizenship) {
+            Some(citizenship) => match get_code_from_name(citizenship) {
                 Some(iso) => iso.to_string(),
                 None => {
                     tracing::error!(
@@ -49,21 +55,40 @@ pub async fn get_largest_billionaires_map(client: &Client) -> Result<HashMap<Str
         };
 
         let name = match billionaire["personName"].as_str() {
-            Some(name) => name.to_string().replace(" & family", ""),
+            Some(name) => name.to_string(),
             None => {
                 tracing::error!("Failed to get name for billionaire: {:?}", billionaire);
                 continue;
             }
         };
 
-        billionaires.entry(citizenship).or_default().push(name);
-    }
-
-    for (_, names) in billionaires.iter_mut() {
-        names.retain(|name| {
-            !name.to_lowercase().contains("chad")
-                && !name.to_lowercase().contains("israel")
-                && !name.to_lowercase().contains("jordan")
+        // "source" lists the companies a fortune comes from, such as "Tesla, SpaceX".
+        let companies = billionaire["source"]
+            .as_str()
+            .map(|source| {
+                source
+                    .split(", ")
+                    .map(|company| company.trim().to_string())
+                    .filter(|company| !company.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        // Forbes URIs are slugs of the name, such as "elon-musk", so one is built from the name when it is missing.
+        let id = match billionaire["uri"].as_str().map(|uri| uri.trim()) {
+            Some(uri) if !uri.is_empty() => uri.to_string(),
+            _ => name
+                .to_lowercase()
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<&str>>()
+                .join("-"),
+        };
+        billionaires.push(Billionaire {
+            id,
+            name,
+            region_code: citizenship,
+            net_worth: final_worth,
+            companies,
         });
     }
 
@@ -1,7 +1,8 @@
 use crate::db::util::url_exists;
 use crate::prelude::*;
 use crate::scrape::util::{
-    get_base_url, get_regions, look_between, notify_parse_fail, strip_html, truncate_string,
+    get_base_url, get_classification, look_between, notify_parse_fail, strip_html, truncate_string,
+    Classification,
 };
 use crate::service::var_service::is_source_enabled;
 use chrono::Local;
@@ -14,7 +15,7 @@ use std::time::Duration;
 pub async fn scrape_antiwar(
     pool: &SqlitePool,
     docker_volume: &str,
-    media: &mut Vec<(String, String, String, Vec<String>)>,
+    media: &mut Vec<(String, String, String, Classification)>,
 ) -> Result<()> {
     let antiwar_enabled: bool = is_source_enabled("ANTIWAR_B").await?;
     if !antiwar_enabled {
@@ -33,8 +34,8 @@ pub async fn scrape_antiwar_features(
     pool: &SqlitePool,
     docker_volume: &str,
     url: &str,
-) -> Result<Vec<(String, String, String, Vec<String>)>> {
-    let mut features: Vec<(String, String, String, Vec<String>)> = Vec::new();
+) -> Result<Vec<(String, String, String, Classification)>> {
+    let mut features: Vec<(String, String, String, Classification)> = Vec::new();
     let response = reqwest::get(url).await?;
     if !response.status().is_success() {
         tracing::debug!("Non-success response from Antiwar: {}", response.status());
@@ -154,8 +155,8 @@ pub async fn scrape_antiwar_features(
         }
         if let Some(body) = body {
             let body = strip_html(&body)?;
-            let regions = get_regions(&[&title, &body]).await?;
-            features.push((url, title, truncate_string(body)?, regions));
+            let classification = get_classification("antiwar", &[&title, &body]).await?;
+            features.push((url, title, truncate_string(body)?, classification));
         }
     }
 
@@ -1,7 +1,8 @@
 use crate::db::util::url_exists;
 use crate::prelude::*;
 use crate::scrape::util::{
-    get_regions, look_between, notify_parse_fail, strip_html, truncate_string,
+    get_classification, look_between, notify_parse_fail, strip_html, truncate_string,
+    Classification,
 };
 use crate::service::var_service::is_source_enabled;
 use chrono::Local;
@@ -9,7 +10,7 @@ use sqlx::SqlitePool;
 
 pub async fn scrape_dn(
     pool: &SqlitePool,
-    media: &mut Vec<(String, String, String, Vec<String>)>,
+    media: &mut Vec<(String, String, String, Classification)>,
 ) -> Result<()> {
     let dn_enabled: bool = is_source_enabled("DN_B").await?;
     if !dn_enabled {
@@ -33,8 +34,8 @@ pub async fn scrape_dn(
 pub async fn scrape_dn_headlines(
     pool: &SqlitePool,
     url: &str,
-) -> Result<Vec<(String, String, String, Vec<String>)>> {
-    let mut headlines: Vec<(String, String, String, Vec<String>)> = Vec::new();
+) -> Result<Vec<(String, String, String, Classification)>> {
+    let mut headlines: Vec<(String, String, String, Classification)> = Vec::new();
     let response = reqwest::get(url).await?;
     if !response.status().is_success() {
         tracing::debug!(
@@ -108,8 +109,9 @@ pub async fn scrape_dn_headlines(
             }
         };
 
-        let regions = get_regions(&[&title, &format!("{} {}", body, tags)]).await?;
-        headlines.push((url, title, truncate_string(body)?, regions));
+        let classification =
+            get_classification("dn", &[&title, &format!("{} {}", body, tags)]).await?;
+        headlines.push((url, title, truncate_string(body)?, classification));
     }
 
     Ok(headlines)
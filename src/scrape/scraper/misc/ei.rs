@@ -1,7 +1,8 @@
 use crate::db::util::url_exists;
 use crate::prelude::*;
 use crate::scrape::util::{
-    get_regions, look_between, notify_parse_fail, strip_html, truncate_string,
+    get_classification, look_between, notify_parse_fail, strip_html, truncate_string,
+    Classification,
 };
 use crate::service::var_service::is_source_enabled;
 use chrono::Local;
@@ -11,7 +12,7 @@ use std::time::Duration;
 
 pub async fn scrape_ei(
     pool: &SqlitePool,
-    media: &mut Vec<(String, String, String, Vec<String>)>,
+    media: &mut Vec<(String, String, String, Classification)>,
 ) -> Result<()> {
     let ei_enabled: bool = is_source_enabled("EI_B").await?;
     if !ei_enabled {
@@ -30,8 +31,8 @@ pub async fn scrape_ei_blogs(
     pool: &SqlitePool,
     url: &str,
     delay: &Duration,
-) -> Result<Vec<(String, String, String, Vec<String>)>> {
-    let mut blogs: Vec<(String, String, String, Vec<String>)> = Vec::new();
+) -> Result<Vec<(String, String, String, Classification)>> {
+    let mut blogs: Vec<(String, String, String, Classification)> = Vec::new();
     let response = reqwest::get(url).await?;
     if !response.status().is_success() {
         tracing::debug!(
@@ -137,8 +138,9 @@ pub async fn scrape_ei_blogs(
             }
         };
 
-        let regions = get_regions(&[&title, &format!("{} {}", body, tags)]).await?;
-        blogs.push((url, title, truncate_string(body)?, regions));
+        let classification =
+            get_classification("ei", &[&title, &format!("{} {}", body, tags)]).await?;
+        blogs.push((url, title, truncate_string(body)?, classification));
     }
 
     Ok(blogs)
@@ -1,7 +1,8 @@
 use crate::db::util::url_exists;
 use crate::prelude::*;
 use crate::scrape::util::{
-    get_regions, look_between, notify_parse_fail, strip_html, truncate_string,
+    get_classification, look_between, notify_parse_fail, strip_html, truncate_string,
+    Classification,
 };
 use crate::service::var_service::is_source_enabled;
 use chrono::Local;
@@ -11,7 +12,7 @@ use std::time::Duration;
 
 pub async fn scrape_ge(
     pool: &SqlitePool,
-    media: &mut Vec<(String, String, String, Vec<String>)>,
+    media: &mut Vec<(String, String, String, Classification)>,
 ) -> Result<()> {
     let ge_enabled: bool = is_source_enabled("GE_B").await?;
     if !ge_enabled {
@@ -35,8 +36,8 @@ pub async fn scrape_ge(
 pub async fn scrape_ge_reports(
     pool: &SqlitePool,
     url: &str,
-) -> Result<Vec<(String, String, String, Vec<String>)>> {
-    let mut reports: Vec<(String, String, String, Vec<String>)> = Vec::new();
+) -> Result<Vec<(String, String, String, Classification)>> {
+    let mut reports: Vec<(String, String, String, Classification)> = Vec::new();
     let response = reqwest::get(url).await?;
     if !response.status().is_success() {
         tracing::debug!(
@@ -124,8 +125,9 @@ pub async fn scrape_ge_reports(
             }
         };
 
-        let regions = get_regions(&[&title, &format!("{} {}", body, tags)]).await?;
-        reports.push((url, title, truncate_string(body)?, regions));
+        let classification =
+            get_classification("ge", &[&title, &format!("{} {}", body, tags)]).await?;
+        reports.push((url, title, truncate_string(body)?, classification));
     }
 
     Ok(reports)
@@ -1,7 +1,8 @@
 use crate::db::util::url_exists;
 use crate::prelude::*;
 use crate::scrape::util::{
-    get_regions, look_between, notify_parse_fail, strip_html, truncate_string,
+    get_classification, look_between, notify_parse_fail, strip_html, truncate_string,
+    Classification,
 };
 use crate::service::var_service::is_source_enabled;
 use chrono::Local;
@@ -9,7 +10,7 @@ use sqlx::SqlitePool;
 
 pub async fn scrape_grayzone(
     pool: &SqlitePool,
-    media: &mut Vec<(String, String, String, Vec<String>)>,
+    media: &mut Vec<(String, String, String, Classification)>,
 ) -> Result<()> {
     let grayzone_enabled: bool = is_source_enabled("GRAYZONE_B").await?;
     if !grayzone_enabled {
@@ -29,8 +30,8 @@ pub async fn scrape_grayzone_stories(
     pool: &SqlitePool,
     url: &str,
     today: String,
-) -> Result<Vec<(String, String, String, Vec<String>)>> {
-    let mut stories: Vec<(String, String, String, Vec<String>)> = Vec::new();
+) -> Result<Vec<(String, String, String, Classification)>> {
+    let mut stories: Vec<(String, String, String, Classification)> = Vec::new();
     let response = reqwest::get(url).await?;
     if !response.status().is_success() {
         tracing::debug!("Non-success response from Grayzone: {}", response.status());
@@ -107,8 +108,9 @@ pub async fn scrape_grayzone_stories(
             }
         };
 
-        let regions = get_regions(&[&title, &format!("{} {}", body, tags)]).await?;
-        stories.push((url, title, truncate_string(body)?, regions));
+        let classification =
+            get_classification("grayzone", &[&title, &format!("{} {}", body, tags)]).await?;
+        stories.push((url, title, truncate_string(body)?, classification));
     }
 
     Ok(stories)
@@ -1,7 +1,8 @@
 use crate::db::util::url_exists;
 use crate::prelude::*;
 use crate::scrape::util::{
-    get_regions, look_between, notify_parse_fail, strip_html, truncate_string,
+    get_classification, look_between, notify_parse_fail, strip_html, truncate_string,
+    Classification,
 };
 use crate::service::var_service::is_source_enabled;
 use chrono::Local;
@@ -9,7 +10,7 @@ use sqlx::SqlitePool;
 
 pub async fn scrape_hrw(
     pool: &SqlitePool,
-    media: &mut Vec<(String, String, String, Vec<String>)>,
+    media: &mut Vec<(String, String, String, Classification)>,
 ) -> Result<()> {
     let hrw_enabled: bool = is_source_enabled("HRW_B").await?;
     if !hrw_enabled {
@@ -24,8 +25,8 @@ pub async fn scrape_hrw(
 pub async fn scrape_hrw_releases(
     pool: &SqlitePool,
     url: &str,
-) -> Result<Vec<(String, String, String, Vec<String>)>> {
-    let mut releases: Vec<(String, String, String, Vec<String>)> = Vec::new();
+) -> Result<Vec<(String, String, String, Classification)>> {
+    let mut releases: Vec<(String, String, String, Classification)> = Vec::new();
     let response = reqwest::get(url).await?;
     if !response.status().is_success() {
         tracing::debug!(
@@ -129,8 +130,9 @@ pub async fn scrape_hrw_releases(
             }
         };
 
-        let regions = get_regions(&[&title, &format!("{} {}", body, tags)]).await?;
-        releases.push((url, title, truncate_string(body)?, regions));
+        let classification =
+            get_classification("hrw", &[&title, &format!("{} {}", body, tags)]).await?;
+        releases.push((url, title, truncate_string(body)?, classification));
     }
 
     Ok(releases)
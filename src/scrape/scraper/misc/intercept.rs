@@ -1,7 +1,8 @@
 use crate::db::util::url_exists;
 use crate::prelude::*;
 use crate::scrape::util::{
-    get_regions, look_between, notify_parse_fail, strip_html, truncate_string,
+    get_classification, look_between, notify_parse_fail, strip_html, truncate_string,
+    Classification,
 };
 use crate::service::var_service::is_source_enabled;
 use chrono::Local;
@@ -9,7 +10,7 @@ use sqlx::SqlitePool;
 
 pub async fn scrape_intercept(
     pool: &SqlitePool,
-    media: &mut Vec<(String, String, String, Vec<String>)>,
+    media: &mut Vec<(String, String, String, Classification)>,
 ) -> Result<()> {
     let intercept_enabled: bool = is_source_enabled("INTERCEPT_B").await?;
     if !intercept_enabled {
@@ -33,8 +34,8 @@ pub async fn scrape_intercept(
 pub async fn scrape_intercept_stories(
     pool: &SqlitePool,
     url: &str,
-) -> Result<Vec<(String, String, String, Vec<String>)>> {
-    let mut stories: Vec<(String, String, String, Vec<String>)> = Vec::new();
+) -> Result<Vec<(String, String, String, Classification)>> {
+    let mut stories: Vec<(String, String, String, Classification)> = Vec::new();
     let response = reqwest::get(url).await?;
     if !response.status().is_success() {
         tracing::debug!(
@@ -98,8 +99,8 @@ pub async fn scrape_intercept_stories(
             }
         };
 
-        let regions = get_regions(&[&title, &body]).await?;
-        stories.push((url, title, truncate_string(body)?, regions));
+        let classification = get_classification("intercept", &[&title, &body]).await?;
+        stories.push((url, title, truncate_string(body)?, classification));
     }
 
     Ok(stories)
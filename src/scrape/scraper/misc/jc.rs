@@ -1,7 +1,8 @@
 use crate::db::util::url_exists;
 use crate::prelude::*;
 use crate::scrape::util::{
-    get_regions, look_between, notify_parse_fail, strip_html, truncate_string,
+    get_classification, look_between, notify_parse_fail, strip_html, truncate_string,
+    Classification,
 };
 use crate::service::var_service::is_source_enabled;
 use chrono::Local;
@@ -9,7 +10,7 @@ use sqlx::SqlitePool;
 
 pub async fn scrape_jc(
     pool: &SqlitePool,
-    media: &mut Vec<(String, String, String, Vec<String>)>,
+    media: &mut Vec<(String, String, String, Classification)>,
 ) -> Result<()> {
     let jc_enabled: bool = is_source_enabled("JC_B").await?;
     if !jc_enabled {
@@ -33,8 +34,8 @@ pub async fn scrape_jc(
 pub async fn scrape_jc_blogs(
     pool: &SqlitePool,
     url: &str,
-) -> Result<Vec<(String, String, String, Vec<String>)>> {
-    let mut blogs: Vec<(String, String, String, Vec<String>)> = Vec::new();
+) -> Result<Vec<(String, String, String, Classification)>> {
+    let mut blogs: Vec<(String, String, String, Classification)> = Vec::new();
     let response = reqwest::get(url).await?;
     if !response.status().is_success() {
         tracing::debug!(
@@ -84,8 +85,8 @@ pub async fn scrape_jc_blogs(
             };
 
         // Crawl delay is five minutes: not worth it for body and tags.
-        let regions = get_regions(&[&title]).await?;
-        blogs.push((url, title.clone(), truncate_string(title)?, regions));
+        let classification = get_classification("jc", &[&title]).await?;
+        blogs.push((url, title.clone(), truncate_string(title)?, classification));
     }
 
     Ok(blogs)
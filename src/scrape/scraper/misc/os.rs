@@ -1,7 +1,8 @@
 use crate::db::util::url_exists;
 use crate::prelude::*;
 use crate::scrape::util::{
-    get_regions, look_between, notify_parse_fail, strip_html, truncate_string,
+    get_classification, look_between, notify_parse_fail, strip_html, truncate_string,
+    Classification,
 };
 use crate::service::var_service::is_source_enabled;
 use chrono::Local;
@@ -9,7 +10,7 @@ use sqlx::SqlitePool;
 
 pub async fn scrape_os(
     pool: &SqlitePool,
-    media: &mut Vec<(String, String, String, Vec<String>)>,
+    media: &mut Vec<(String, String, String, Classification)>,
 ) -> Result<()> {
     let os_enabled: bool = is_source_enabled("OS_B").await?;
     if !os_enabled {
@@ -43,8 +44,8 @@ pub async fn scrape_os(
 pub async fn scrape_os_news(
     pool: &SqlitePool,
     url: &str,
-) -> Result<Vec<(String, String, String, Vec<String>)>> {
-    let mut news: Vec<(String, String, String, Vec<String>)> = Vec::new();
+) -> Result<Vec<(String, String, String, Classification)>> {
+    let mut news: Vec<(String, String, String, Classification)> = Vec::new();
     let response = reqwest::get(url).await?;
     if !response.status().is_success() {
         tracing::debug!(
@@ -128,8 +129,8 @@ pub async fn scrape_os_news(
             }
         };
 
-        let regions = get_regions(&[&title, &body]).await?;
-        news.push((url, title, truncate_string(body)?, regions));
+        let classification = get_classification("os", &[&title, &body]).await?;
+        news.push((url, title, truncate_string(body)?, classification));
     }
 
     Ok(news)
@@ -138,8 +139,8 @@ pub async fn scrape_os_news(
 pub async fn scrape_os_reports(
     pool: &SqlitePool,
     url: &str,
-) -> Result<Vec<(String, String, String, Vec<String>)>> {
-    let mut reports: Vec<(String, String, String, Vec<String>)> = Vec::new();
+) -> Result<Vec<(String, String, String, Classification)>> {
+    let mut reports: Vec<(String, String, String, Classification)> = Vec::new();
     let response = reqwest::get(url).await?;
     if !response.status().is_success() {
         tracing::debug!(
@@ -222,8 +223,8 @@ pub async fn scrape_os_reports(
             }
         };
 
-        let regions = get_regions(&[&title, &body]).await?;
-        reports.push((url, title, truncate_string(body)?, regions));
+        let classification = get_classification("os", &[&title, &body]).await?;
+        reports.push((url, title, truncate_string(body)?, classification));
     }
 
     Ok(reports)
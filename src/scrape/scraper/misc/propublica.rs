@@ -1,7 +1,8 @@
 use crate::db::util::url_exists;
 use crate::prelude::*;
 use crate::scrape::util::{
-    get_regions, look_between, notify_parse_fail, strip_html, truncate_string,
+    get_classification, look_between, notify_parse_fail, strip_html, truncate_string,
+    Classification,
 };
 use crate::service::var_service::is_source_enabled;
 use chrono::{DateTime, FixedOffset, Local, NaiveDateTime};
@@ -9,7 +10,7 @@ use sqlx::SqlitePool;
 
 pub async fn scrape_propublica(
     pool: &SqlitePool,
-    media: &mut Vec<(String, String, String, Vec<String>)>,
+    media: &mut Vec<(String, String, String, Classification)>,
 ) -> Result<()> {
     let propublica_enabled: bool = is_source_enabled("PROPUBLICA_B").await?;
     if !propublica_enabled {
@@ -33,8 +34,8 @@ pub async fn scrape_propublica(
 pub async fn scrape_propublica_news(
     pool: &SqlitePool,
     url: &str,
-) -> Result<Vec<(String, String, String, Vec<String>)>> {
-    let mut news: Vec<(String, String, String, Vec<String>)> = Vec::new();
+) -> Result<Vec<(String, String, String, Classification)>> {
+    let mut news: Vec<(String, String, String, Classification)> = Vec::new();
     let response = reqwest::get(url).await?;
     if !response.status().is_success() {
         tracing::debug!(
@@ -129,8 +130,8 @@ pub async fn scrape_propublica_news(
             }
         };
 
-        let regions = get_regions(&[&title, &body]).await?;
-        news.push((url, title, truncate_string(body)?, regions));
+        let classification = get_classification("propublica", &[&title, &body]).await?;
+        news.push((url, title, truncate_string(body)?, classification));
     }
 
     Ok(news)
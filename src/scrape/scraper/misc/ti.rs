@@ -1,7 +1,8 @@
 use crate::db::util::url_exists;
 use crate::prelude::*;
 use crate::scrape::util::{
-    get_regions, look_between, notify_parse_fail, strip_html, truncate_string,
+    get_classification, look_between, notify_parse_fail, strip_html, truncate_string,
+    Classification,
 };
 use crate::service::var_service::is_source_enabled;
 use chrono::Local;
@@ -9,7 +10,7 @@ use sqlx::SqlitePool;
 
 pub async fn scrape_ti(
     pool: &SqlitePool,
-    media: &mut Vec<(String, String, String, Vec<String>)>,
+    media: &mut Vec<(String, String, String, Classification)>,
 ) -> Result<()> {
     let ti_enabled: bool = is_source_enabled("TI_B").await?;
     if !ti_enabled {
@@ -34,8 +35,8 @@ pub async fn scrape_ti(
 pub async fn scrape_ti_investigations(
     pool: &SqlitePool,
     url: &str,
-) -> Result<Vec<(String, String, String, Vec<String>)>> {
-    let mut investigations: Vec<(String, String, String, Vec<String>)> = Vec::new();
+) -> Result<Vec<(String, String, String, Classification)>> {
+    let mut investigations: Vec<(String, String, String, Classification)> = Vec::new();
     let response = reqwest::get(url).await?;
     if !response.status().is_success() {
         tracing::debug!(
@@ -96,8 +97,8 @@ pub async fn scrape_ti_investigations(
             }
         };
 
-        let regions = get_regions(&[&title, &body]).await?;
-        investigations.push((url, title, truncate_string(body)?, regions));
+        let classification = get_classification("ti", &[&title, &body]).await?;
+        investigations.push((url, title, truncate_string(body)?, classification));
     }
 
     Ok(investigations)
@@ -1,7 +1,8 @@
 use crate::db::util::url_exists;
 use crate::prelude::*;
 use crate::scrape::util::{
-    get_regions, look_between, notify_parse_fail, strip_html, truncate_string,
+    get_classification, look_between, notify_parse_fail, strip_html, truncate_string,
+    Classification,
 };
 use crate::service::var_service::is_source_enabled;
 use chrono::Local;
@@ -9,7 +10,7 @@ use sqlx::SqlitePool;
 
 pub async fn scrape_ur(
     pool: &SqlitePool,
-    media: &mut Vec<(String, String, String, Vec<String>)>,
+    media: &mut Vec<(String, String, String, Classification)>,
 ) -> Result<()> {
     let ur_enabled: bool = is_source_enabled("UR_B").await?;
     if !ur_enabled {
@@ -24,8 +25,8 @@ pub async fn scrape_ur(
 pub async fn scrape_ur_posts(
     pool: &SqlitePool,
     url: &str,
-) -> Result<Vec<(String, String, String, Vec<String>)>> {
-    let mut posts: Vec<(String, String, String, Vec<String>)> = Vec::new();
+) -> Result<Vec<(String, String, String, Classification)>> {
+    let mut posts: Vec<(String, String, String, Classification)> = Vec::new();
     let response = reqwest::get(url).await?;
     if !response.status().is_success() {
         tracing::debug!(
@@ -127,8 +128,9 @@ pub async fn scrape_ur_posts(
                 }
             };
 
-        let regions = get_regions(&[&title, &format!("{} {:?}", body, tags)]).await?;
-        posts.push((url, title, truncate_string(body)?, regions));
+        let classification =
+            get_classification("ur", &[&title, &format!("{} {:?}", body, tags)]).await?;
+        posts.push((url, title, truncate_string(body)?, classification));
     }
 
     Ok(posts)
@@ -0,0 +1,394 @@
+use crate::db::keyphrase::SourcePaths;
+use crate::prelude::*;
+use crate::scrape::iso3166::{get_code_from_name, get_country};
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+use reqwest::Client;
+use std::{collections::HashMap, fs::read_to_string, str::from_utf8};
+
+const OFAC_SDN_URL: &str = "https://www.treasury.gov/ofac/downloads/sdn.csv";
+const OFAC_ADD_URL: &str = "https://www.treasury.gov/ofac/downloads/add.csv";
+const UN_URL: &str = "https://scsanctions.un.org/resources/xml/en/consolidated.xml";
+const EU_URL: &str =
+    "https://webgate.ec.europa.eu/fsd/fsf/public/files/xmlFullSanctionsList_1_1/content?token=dG9rZW4tMjAxNw";
+
+// Sanctioned people, entities, and vessels of the OFAC SDN, UN consolidated, and EU consolidated lists, as
+// (list ID, name) pairs by region code. Each list is read from its local path when given, and downloaded otherwise.
+pub async fn get_sanctioned_map(
+    client: &Client,
+    paths: &SourcePaths,
+) -> Result<HashMap<String, Vec<(String, String)>>> {
+    let mut sanctioned: HashMap<String, Vec<(String, String)>> = HashMap::new();
+    let mut designations = Vec::new();
+    if let Some(sdn) = get_list(client, paths.ofac_sdn.as_deref(), OFAC_SDN_URL).await? {
+        let addresses = get_list(client, paths.ofac_add.as_deref(), OFAC_ADD_URL).await?;
+        designations.extend(parse_ofac_sdn(&sdn, addresses.as_deref()));
+    }
+    if let Some(xml) = get_list(client, paths.un_sanctions.as_deref(), UN_URL).await? {
+        match parse_un_list(&xml) {
+            Ok(un_designations) => designations.extend(un_designations),
+            Err(err) => tracing::error!("Failed to parse the UN sanctions list: {}", err),
+        }
+    }
+    if let Some(xml) = get_list(client, paths.eu_sanctions.as_deref(), EU_URL).await? {
+        match parse_eu_list(&xml) {
+            Ok(eu_designations) => designations.extend(eu_designations),
+            Err(err) => tracing::error!("Failed to parse the EU sanctions list: {}", err),
+        }
+    }
+
+    for (id, name, region_codes) in designations {
+        // Single words, such as vessel names, are too common in text to identify anyone.
+        if !name.trim().contains(' ') {
+            continue;
+        }
+
+        for region_code in region_codes {
+            let entries = sanctioned.entry(region_code).or_default();
+            if !entries.iter().any(|(entry_id, _)| *entry_id == id) {
+                entries.push((id.clone(), name.clone()));
+            }
+        }
+    }
+
+    Ok(sanctioned)
+}
+
+async fn get_list(client: &Client, path: Option<&str>, url: &str) -> Result<Option<String>> {
+    if let Some(path) = path {
+        return Ok(Some(read_to_string(path)?));
+    }
+
+    // An unreachable list is skipped like one that fails to respond, so the other lists are still read.
+    let response = match client.get(url).send().await {
+        Ok(response) if response.status().is_success() => response,
+        Ok(response) => {
+            tracing::error!("Non-success response from {}: {}", url, response.status());
+            return Ok(None);
+        }
+        Err(err) => {
+            tracing::error!("Failed to request {}: {}", url, err);
+            return Ok(None);
+        }
+    };
+
+    match response.text().await {
+        Ok(text) => Ok(Some(text)),
+        Err(err) => {
+            tracing::error!("Failed to read response from {}: {}", url, err);
+            Ok(None)
+        }
+    }
+}
+
+// sdn.csv has no header: ent_num, SDN_Name, SDN_Type, Program, Title, Call_Sign, Vess_type, Tonnage, GRT,
+// Vess_flag, Vess_owner, Remarks. Countries come from the "nationality" entries of the semicolon-separated remarks and the
+// addresses in add.csv, whose rows are ent_num, add_num, address, city, country, remarks. Vessel flags are left out, as a
+// flag of convenience says little about who is sanctioned. "-0-" marks an empty field.
+fn parse_ofac_sdn(sdn: &str, addresses: Option<&str>) -> Vec<(String, String, Vec<String>)> {
+    let mut countries: HashMap<String, Vec<String>> = HashMap::new();
+    for fields in addresses.unwrap_or_default().lines().map(parse_csv_line) {
+        if let (Some(ent_num), Some(country)) = (fields.first(), get_csv_field(&fields, 4)) {
+            countries
+                .entry(ent_num.to_string())
+                .or_default()
+                .push(country.to_string());
+        }
+    }
+
+    let mut designations = Vec::new();
+    for fields in sdn.lines().map(parse_csv_line) {
+        let (Some(ent_num), Some(name)) = (get_csv_field(&fields, 0), get_csv_field(&fields, 1))
+        else {
+            continue;
+        };
+
+        // Individuals are listed as "LAST, First".
+        let name = match (get_csv_field(&fields, 2), name.split_once(", ")) {
+            (Some("individual"), Some((last_name, first_name))) => {
+                format!("{} {}", first_name, last_name)
+            }
+            _ => name.to_string(),
+        };
+        let mut country_names = countries.remove(ent_num).unwrap_or_default();
+        for remark in get_csv_field(&fields, 11).unwrap_or_default().split(';') {
+            let remark = remark.trim().trim_end_matches('.');
+            if let Some(nationality) = remark
+                .strip_prefix("nationality ")
+                .or_else(|| remark.strip_prefix("alt. nationality "))
+            {
+                country_names.push(nationality.to_string());
+            }
+        }
+        designations.push((
+            format!("ofac:{ent_num}"),
+            name,
+            get_region_codes(country_names.iter().map(|country| country.as_str())),
+        ));
+    }
+
+    designations
+}
+
+fn parse_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.trim_end_matches('\r').chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+
+    fields
+}
+
+fn get_csv_field(fields: &[String], index: usize) -> Option<&str> {
+    fields
+        .get(index)
+        .map(|field| field.trim())
+        .filter(|field| !field.is_empty() && *field != "-0-")
+}
+
+// Individuals' names are split into FIRST_NAME to FOURTH_NAME, while entities only use FIRST_NAME. Countries come
+// from nationalities and addresses.
+fn parse_un_list(xml: &str) -> Result<Vec<(String, String, Vec<String>)>> {
+    let mut designations = Vec::new();
+    let mut reference = None;
+    let mut names: [Option<String>; 4] = Default::default();
+    let mut country_names = Vec::new();
+    for_each_element(xml, |parent, name, _, text| match (parent, name) {
+        ("INDIVIDUAL" | "ENTITY", "REFERENCE_NUMBER") => reference = Some(text.to_string()),
+        ("INDIVIDUAL" | "ENTITY", "FIRST_NAME") => names[0] = Some(text.to_string()),
+        ("INDIVIDUAL" | "ENTITY", "SECOND_NAME") => names[1] = Some(text.to_string()),
+        ("INDIVIDUAL" | "ENTITY", "THIRD_NAME") => names[2] = Some(text.to_string()),
+        ("INDIVIDUAL" | "ENTITY", "FOURTH_NAME") => names[3] = Some(text.to_string()),
+        ("NATIONALITY", "VALUE") | ("INDIVIDUAL_ADDRESS" | "ENTITY_ADDRESS", "COUNTRY") => {
+            country_names.push(text.to_string())
+        }
+        ("INDIVIDUALS", "INDIVIDUAL") | ("ENTITIES", "ENTITY") => {
+            let names: Vec<String> = std::mem::take(&mut names)
+                .into_iter()
+                .flatten()
+                .filter(|name| !name.is_empty())
+                .collect();
+            let country_names = std::mem::take(&mut country_names);
+            if let Some(reference) = reference.take() {
+                designations.push((
+                    format!("un:{reference}"),
+                    names.join(" "),
+                    get_region_codes(country_names.iter().map(|country| country.as_str())),
+                ));
+            }
+        }
+        _ => (),
+    })?;
+
+    Ok(designations)
+}
+
+// Each sanctionEntity lists its names as nameAlias elements, the first being the primary one, and its countries as
+// ISO codes on citizenship and address elements.
+fn parse_eu_list(xml: &str) -> Result<Vec<(String, String, Vec<String>)>> {
+    let mut designations = Vec::new();
+    let mut name = None;
+    let mut region_codes: Vec<String> = Vec::new();
+    for_each_element(xml, |_, element, attributes, _| match element {
+        "nameAlias" if name.is_none() => {
+            name = attributes
+                .get("wholeName")
+                .filter(|whole_name| !whole_name.is_empty())
+                .cloned()
+        }
+        "citizenship" | "address" => {
+            let Some(country) = attributes
+                .get("countryIso2Code")
+                .and_then(|code| get_country(code))
+            else {
+                return;
+            };
+
+            if !region_codes
+                .iter()
+                .any(|region_code| region_code == country.alpha2)
+            {
+                region_codes.push(country.alpha2.to_string());
+            }
+        }
+        "sanctionEntity" => {
+            let (name, region_codes) = (name.take(), std::mem::take(&mut region_codes));
+            if let (Some(logical_id), Some(name)) = (attributes.get("logicalId"), name) {
+                designations.push((format!("eu:{logical_id}"), name, region_codes));
+            }
+        }
+        _ => (),
+    })?;
+
+    Ok(designations)
+}
+
+// Calls on_element with the local names of each element and its parent, its attributes, and its trimmed text once the
+// element closes, so an element's children are always visited before the element itself.
+fn for_each_element(
+    xml: &str,
+    mut on_element: impl FnMut(&str, &str, &HashMap<String, String>, &str),
+) -> Result<()> {
+    let mut reader = Reader::from_str(xml);
+    let mut open: Vec<(String, HashMap<String, String>, String)> = Vec::new();
+    loop {
+        match reader.read_event()? {
+            Event::Start(start) => open.push((
+                from_utf8(start.local_name().as_ref())?.to_string(),
+                get_xml_attributes(&start)?,
+                String::new(),
+            )),
+            Event::Empty(empty) => {
+                let parent = open.last().map(|(name, _, _)| name.as_str());
+                on_element(
+                    parent.unwrap_or_default(),
+                    from_utf8(empty.local_name().as_ref())?,
+                    &get_xml_attributes(&empty)?,
+                    "",
+                );
+            }
+            Event::Text(text) => {
+                if let Some((_, _, content)) = open.last_mut() {
+                    content.push_str(&text.unescape()?);
+                }
+            }
+            Event::CData(cdata) => {
+                if let Some((_, _, content)) = open.last_mut() {
+                    content.push_str(from_utf8(&cdata)?);
+                }
+            }
+            Event::End(_) => {
+                if let Some((name, attributes, content)) = open.pop() {
+                    let parent = open.last().map(|(name, _, _)| name.as_str());
+                    on_element(
+                        parent.unwrap_or_default(),
+                        &name,
+                        &attributes,
+                        content.trim(),
+                    );
+                }
+            }
+            Event::Eof => break,
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
+
+fn get_xml_attributes(element: &BytesStart) -> Result<HashMap<String, String>> {
+    let mut attributes = HashMap::new();
+    for attribute in element.attributes() {
+        let attribute = attribute?;
+        attributes.insert(
+            from_utf8(attribute.key.local_name().as_ref())?.to_string(),
+            attribute.unescape_value()?.trim().to_string(),
+        );
+    }
+
+    Ok(attributes)
+}
+
+fn get_region_codes<'a>(country_names: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let mut region_codes = Vec::new();
+    for country_name in country_names {
+        match get_code_from_name(country_name) {
+            Some(region_code) if !region_codes.iter().any(|code| code == region_code) => {
+                region_codes.push(region_code.to_string())
+            }
+            Some(_) => (),
+            None => tracing::debug!("No ISO code found for sanctions country: {}", country_name),
+        }
+    }
+
+    region_codes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_triples(
+        designations: Vec<(String, String, Vec<String>)>,
+    ) -> Vec<(String, String, String)> {
+        designations
+            .into_iter()
+            .flat_map(|(id, name, region_codes)| {
+                region_codes
+                    .into_iter()
+                    .map(move |region_code| (id.clone(), name.clone(), region_code))
+            })
+            .collect()
+    }
+
+    fn triple(id: &str, name: &str, region_code: &str) -> (String, String, String) {
+        (id.to_string(), name.to_string(), region_code.to_string())
+    }
+
+    #[test]
+    fn parses_ofac_sdn() {
+        let designations = parse_ofac_sdn(
+            include_str!("../../../tests/fixtures/sanctions/sdn.csv"),
+            Some(include_str!("../../../tests/fixtures/sanctions/add.csv")),
+        );
+
+        assert_eq!(
+            get_triples(designations),
+            vec![
+                triple("ofac:36", "AEROCARIBBEAN AIRLINES", "cu"),
+                triple("ofac:173", "ANGLO-CARIBBEAN CO., LTD.", "gb"),
+                triple("ofac:2674", "Saddam HUSSEIN", "iq"),
+                triple("ofac:20291", "Jong Un KIM", "kp"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_un_list() {
+        let designations =
+            parse_un_list(include_str!("../../../tests/fixtures/sanctions/un.xml")).unwrap();
+
+        assert_eq!(
+            get_triples(designations),
+            vec![
+                triple("un:KPi.033", "RI WON HO", "kp"),
+                triple("un:KPi.033", "RI WON HO", "sy"),
+                triple("un:TAi.013", "ABDUL BAQI & BASIR", "af"),
+                triple(
+                    "un:QDe.109",
+                    "AL-HARAMAIN FOUNDATION (UNION OF THE COMOROS)",
+                    "km"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_eu_list() {
+        let designations =
+            parse_eu_list(include_str!("../../../tests/fixtures/sanctions/eu.xml")).unwrap();
+
+        assert_eq!(
+            get_triples(designations),
+            vec![
+                triple("eu:13", "Saddam Hussein Al-Tikriti", "iq"),
+                triple("eu:120", "Sea & Air \"Transport\" Company", "kp"),
+                triple("eu:120", "Sea & Air \"Transport\" Company", "ru"),
+            ]
+        );
+    }
+}
@@ -1,7 +1,8 @@
 use crate::db::util::url_exists;
 use crate::prelude::*;
 use crate::scrape::util::{
-    get_regions, look_between, notify_parse_fail, strip_html, truncate_string,
+    get_classification, look_between, notify_parse_fail, strip_html, truncate_string,
+    Classification,
 };
 use crate::service::var_service::get_substack_urls;
 use chrono::Local;
@@ -9,7 +10,7 @@ use sqlx::SqlitePool;
 
 pub async fn scrape_substack(
     pool: &SqlitePool,
-    media: &mut Vec<(String, String, String, Vec<String>)>,
+    media: &mut Vec<(String, String, String, Classification)>,
 ) -> Result<()> {
     let substack_urls = match get_substack_urls().await? {
         Some(urls) => urls,
@@ -30,8 +31,8 @@ pub async fn scrape_substack(
 pub async fn scrape_substack_archive(
     pool: &SqlitePool,
     url: &str,
-) -> Result<Vec<(String, String, String, Vec<String>)>> {
-    let mut letters: Vec<(String, String, String, Vec<String>)> = Vec::new();
+) -> Result<Vec<(String, String, String, Classification)>> {
+    let mut letters: Vec<(String, String, String, Classification)> = Vec::new();
     let response = reqwest::get(url).await?;
     if !response.status().is_success() {
         tracing::debug!("Non-success response from Substack: {}", response.status());
@@ -130,8 +131,8 @@ pub async fn scrape_substack_archive(
             }
         };
 
-        let regions = get_regions(&[&title, &body]).await?;
-        letters.push((url, title, truncate_string(body)?, regions));
+        let classification = get_classification("substack", &[&title, &body]).await?;
+        letters.push((url, title, truncate_string(body)?, classification));
     }
 
     Ok(letters)
@@ -1,88 +1,569 @@
 use crate::prelude::*;
-use crate::scrape::util::get_iso_from_name;
+use crate::scrape::iso3166::get_code_from_name;
+use anyhow::anyhow;
 use reqwest::Client;
 use serde_json::{from_str, Value};
-use std::collections::HashMap;
+use std::fs::read_to_string;
 use wikitext_table_parser::{
-    parser::{Event, WikitextTableParser},
+    parser::{CellType, Event, WikitextTableParser},
     tokenizer::{get_all_cell_text_special_tokens, get_all_table_special_tokens, Tokenizer},
 };
 
-pub async fn get_private_enterprises_map(client: &Client) -> Result<HashMap<String, Vec<String>>> {
-    let mut region_to_companies: HashMap<String, Vec<String>> = HashMap::new();
-    let url = "https://en.wikipedia.org/w/api.php?action=query&prop=revisions&rvprop=content&rvslots=main&format=json&titles=List_of_largest_private_non-governmental_companies_by_revenue";
-    let response = client.get(url).send().await?;
-    if !response.status().is_success() {
-        tracing::debug!("Non-success response from Wikipedia: {}", response.status());
-        return Ok(region_to_companies);
-    }
+// Rows are kept when the column's text contains (or, with excludes, lacks) the given text, ignoring case.
+struct ColumnFilter {
+    column: String,
+    contains: Option<String>,
+    excludes: Option<String>,
+}
 
-    let text = response.text().await?;
-    let parsed: Value = from_str(&text)?;
-    let content = match parsed["query"]["pages"].as_object().and_then(|pages| {
-        pages
-            .values()
-            .next()
-            .and_then(|page| page["revisions"][0]["slots"]["main"]["*"].as_str())
-    }) {
-        Some(content) => content,
-        None => {
-            tracing::error!("Failed to get content from Wikipedia response");
-            return Ok(region_to_companies);
-        }
+// A Wikipedia page whose tables list entities by country. Columns are matched by the start of any of their header texts,
+// and pages about a single country can give its region code instead of a country column.
+pub struct TableSource {
+    title: String,
+    wikitext_path: Option<String>,
+    pub category: String,
+    entity_column: String,
+    country_column: Option<String>,
+    region_code: Option<String>,
+    filters: Vec<ColumnFilter>,
+}
+
+pub struct TableEntry {
+    pub region_code: String,
+    pub name: String,
+    pub source_id: String,
+}
+
+// Header texts per column, and rows of cell texts.
+type Table = (Vec<Vec<String>>, Vec<Vec<String>>);
+
+struct Cell {
+    text: String,
+    header: bool,
+    colspan: usize,
+    rowspan: usize,
+}
+
+// The largest private companies page unless a JSON array of sources is given. A saved copy of the default page's
+// wikitext can be given in place of the Wikipedia API, as can each configured source's "path".
+pub fn load_table_sources(
+    sources_path: Option<&str>,
+    wikitext_path: Option<&str>,
+) -> Result<Vec<TableSource>> {
+    let Some(sources_path) = sources_path else {
+        return Ok(vec![TableSource {
+            title: "List_of_largest_private_non-governmental_companies_by_revenue".to_string(),
+            wikitext_path: wikitext_path.map(|path| path.to_string()),
+            category: "enterprise".to_string(),
+            entity_column: "name".to_string(),
+            country_column: Some("headquarters".to_string()),
+            region_code: None,
+            filters: Vec::new(),
+        }]);
     };
 
-    let mut current_region;
-    let mut current_enterprise = String::new();
-    let wikitext_table_parser = WikitextTableParser::new(
-        Tokenizer::build(get_all_table_special_tokens()),
-        Tokenizer::build(get_all_cell_text_special_tokens()),
-        content,
-        true,
-    );
+    let json: Value = from_str(&read_to_string(sources_path)?)?;
+    let Some(sources) = json.as_array() else {
+        let err = format!("{sources_path} is not a JSON array of table sources");
+        tracing::error!(err);
+        return Err(anyhow!(err));
+    };
+
+    let mut table_sources = Vec::new();
+    for source in sources {
+        let (Some(title), Some(entity_column)) =
+            (source["title"].as_str(), source["entity_column"].as_str())
+        else {
+            let err = format!("Table source lacks a title or entity_column: {source}");
+            tracing::error!(err);
+            return Err(anyhow!(err));
+        };
+
+        let country_column = source["country_column"].as_str();
+        let region_code = source["region_code"].as_str();
+        if country_column.is_none() && region_code.is_none() {
+            let err = format!("Table source {title} lacks a country_column or region_code");
+            tracing::error!(err);
+            return Err(anyhow!(err));
+        }
+
+        table_sources.push(TableSource {
+            title: title.replace(' ', "_"),
+            wikitext_path: source["path"].as_str().map(|path| path.to_string()),
+            category: source["category"]
+                .as_str()
+                .unwrap_or("enterprise")
+                .to_string(),
+            entity_column: entity_column.to_lowercase(),
+            country_column: country_column.map(|column| column.to_lowercase()),
+            region_code: region_code.map(|code| code.to_lowercase()),
+            filters: source["filters"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|filter| {
+                    Some(ColumnFilter {
+                        column: filter["column"].as_str()?.to_lowercase(),
+                        contains: filter["contains"].as_str().map(|text| text.to_lowercase()),
+                        excludes: filter["excludes"].as_str().map(|text| text.to_lowercase()),
+                    })
+                })
+                .collect(),
+        });
+    }
+
+    Ok(table_sources)
+}
 
-    for event in wikitext_table_parser {
-        let Event::ColEnd(text) = event else {
+// Entities in the rows of every table on the source's page that has its columns. An entity's ID is the article its
+// cell links to, or its name.
+pub async fn get_table_entries(client: &Client, source: &TableSource) -> Result<Vec<TableEntry>> {
+    let content = match &source.wikitext_path {
+        Some(wikitext_path) => read_to_string(wikitext_path)?,
+        None => match get_wikitext(client, &source.title).await? {
+            Some(content) => content,
+            None => return Ok(Vec::new()),
+        },
+    };
+
+    let mut entries = Vec::new();
+    for (headers, rows) in get_tables(&content) {
+        let find_column = |column: &str| {
+            headers.iter().position(|header_texts| {
+                header_texts
+                    .iter()
+                    .any(|header| clean_wikitext(header).to_lowercase().starts_with(column))
+            })
+        };
+        let Some(entity_index) = find_column(&source.entity_column) else {
             continue;
         };
 
-        let first_char = match text.chars().next() {
-            Some(first_char) => first_char,
-            None => continue,
+        let country_index = match &source.country_column {
+            Some(country_column) => match find_column(country_column) {
+                Some(country_index) => Some(country_index),
+                None => continue,
+            },
+            None => None,
         };
+        let filters: Vec<(Option<usize>, &ColumnFilter)> = source
+            .filters
+            .iter()
+            .map(|filter| (find_column(&filter.column), filter))
+            .collect();
 
-        if text.starts_with("[[") && text.ends_with("]]") {
-            current_enterprise = text[2..text.len() - 2].to_string();
-            if !current_enterprise.contains('|') {
+        for row in rows {
+            let matches_filters = filters.iter().all(|(index, filter)| {
+                let text = index
+                    .and_then(|index| row.get(index))
+                    .map(|cell| clean_wikitext(cell).to_lowercase())
+                    .unwrap_or_default();
+                filter
+                    .contains
+                    .as_ref()
+                    .is_none_or(|contains| text.contains(contains))
+                    && filter
+                        .excludes
+                        .as_ref()
+                        .is_none_or(|excludes| !text.contains(excludes))
+            });
+            if !matches_filters {
                 continue;
             }
 
-            current_enterprise = match current_enterprise.split('|').last() {
-                Some(current_enterprise) => current_enterprise.to_string(),
-                None => continue,
+            let Some(cell) = row.get(entity_index) else {
+                continue;
             };
-        } else if !first_char.is_numeric() && !text.starts_with('[') && !text.starts_with('"') {
-            current_region = text.to_string();
-            if !current_region.contains('.') {
+
+            let name = clean_wikitext(cell);
+            if name.chars().count() < 2 {
                 continue;
             }
 
-            current_region = match current_region.split('.').next() {
-                Some(current_region) => current_region.to_string(),
-                None => continue,
+            let region_code = match (country_index, &source.region_code) {
+                (Some(country_index), _) => {
+                    match row
+                        .get(country_index)
+                        .and_then(|cell| get_country_code(cell))
+                    {
+                        Some(region_code) => region_code,
+                        None => {
+                            tracing::debug!("No ISO code found for the country of {name}");
+                            continue;
+                        }
+                    }
+                }
+                (None, Some(region_code)) => region_code.to_string(),
+                (None, None) => continue,
             };
+            entries.push(TableEntry {
+                region_code,
+                source_id: get_link_target(cell).unwrap_or(name.clone()),
+                name,
+            });
+        }
+    }
 
-            let current_region_code = match get_iso_from_name(&current_region) {
-                Some(current_region_code) => current_region_code.to_string(),
-                None => continue,
-            };
+    Ok(entries)
+}
+
+// Header texts of each column and data rows of each table, with rowspan and colspan cells repeated into the rows and
+// columns they cover. Leading rows made only of header cells are headers, so a column under a multi-row header has
+// one text per header row, such as "Headquarters" and "Country".
+fn get_tables(content: &str) -> Vec<Table> {
+    let normalized = normalize_rows(content);
+    let parser = WikitextTableParser::new(
+        Tokenizer::build(get_all_table_special_tokens()),
+        Tokenizer::build(get_all_cell_text_special_tokens()),
+        &normalized,
+        true,
+    );
+
+    let mut tables = Vec::new();
+    let mut headers: Vec<Vec<String>> = Vec::new();
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut cells: Vec<Cell> = Vec::new();
+    let mut header_spans: Vec<(String, usize)> = Vec::new(); // Text and remaining rows per column.
+    let mut spans: Vec<(String, usize)> = Vec::new();
+    let mut header_cell = false;
+    let mut style = String::new();
+    for event in parser {
+        match event {
+            Event::TableStart => {
+                headers.clear();
+                rows.clear();
+                header_spans.clear();
+                spans.clear();
+            }
+            Event::RowStart => cells.clear(),
+            Event::ColStart(cell_type) => {
+                header_cell = matches!(cell_type, CellType::HeaderCell);
+                style.clear();
+            }
+            Event::ColStyle(cell_style) => style = cell_style,
+            Event::ColEnd(text) => cells.push(Cell {
+                text: text.trim().to_string(),
+                header: header_cell,
+                colspan: get_span(&style, "colspan"),
+                rowspan: get_span(&style, "rowspan"),
+            }),
+            Event::RowEnd => {
+                if cells.is_empty() {
+                    continue;
+                }
+
+                // Row headers, such as "! scope=row |", can open data rows, which are never headers.
+                if rows.is_empty() && cells.iter().all(|cell| cell.header) {
+                    let row = get_row(&cells, &mut header_spans);
+                    if headers.len() < row.len() {
+                        headers.resize(row.len(), Vec::new());
+                    }
+                    for (column, text) in row.into_iter().enumerate() {
+                        if !text.is_empty() && headers[column].last() != Some(&text) {
+                            headers[column].push(text);
+                        }
+                    }
+                    continue;
+                }
+
+                let row = get_row(&cells, &mut spans);
+                if !row.is_empty() {
+                    rows.push(row);
+                }
+            }
+            Event::TableEnd => {
+                tables.push((headers.clone(), rows.clone()));
+            }
+            _ => (),
+        }
+    }
+
+    tables
+}
+
+// The parser only types the first cell of each row, so header cells of rows that also have data cells, such as row
+// headers, become data cells. A header row right after the table's opening line would also be read as the table's
+// style, so it is given its own row.
+fn normalize_rows(content: &str) -> String {
+    let mut normalized = String::with_capacity(content.len());
+    let mut row: Vec<&str> = Vec::new();
+    let mut table_start = false;
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if !["{|", "|-", "|}"]
+            .iter()
+            .any(|start| trimmed.starts_with(start))
+        {
+            row.push(line);
+            continue;
+        }
+
+        push_row(&mut normalized, &mut row, table_start);
+        normalized.push_str(line);
+        normalized.push('\n');
+        table_start = trimmed.starts_with("{|");
+    }
+    push_row(&mut normalized, &mut row, table_start);
 
-            region_to_companies
-                .entry(current_region_code)
-                .or_default()
-                .push(current_enterprise.clone());
+    normalized
+}
+
+fn push_row(normalized: &mut String, row: &mut Vec<&str>, table_start: bool) {
+    let has_data_cells = row.iter().any(|line| {
+        let line = line.trim_start();
+        line.starts_with('|') && !line.starts_with("|+")
+    });
+    let opens_with_header = row
+        .iter()
+        .map(|line| line.trim_start())
+        .find(|line| !line.is_empty())
+        .is_some_and(|line| line.starts_with('!'));
+    if table_start && opens_with_header && !has_data_cells {
+        normalized.push_str("|-\n");
+    }
+
+    for line in row.drain(..) {
+        match (has_data_cells, line.trim_start().strip_prefix('!')) {
+            (true, Some(cell)) => {
+                normalized.push('|');
+                normalized.push_str(&cell.replace("!!", "||"));
+            }
+            _ => normalized.push_str(line),
+        }
+        normalized.push('\n');
+    }
+}
+
+// Lays a row's cells out into columns, after the cells of rows above that span into it.
+fn get_row(cells: &[Cell], spans: &mut Vec<(String, usize)>) -> Vec<String> {
+    let mut row = Vec::new();
+    for cell in cells {
+        fill_spanned_cells(&mut row, spans);
+        for _ in 0..cell.colspan {
+            if cell.rowspan > 1 {
+                if spans.len() <= row.len() {
+                    spans.resize(row.len() + 1, (String::new(), 0));
+                }
+                spans[row.len()] = (cell.text.clone(), cell.rowspan - 1);
+            }
+            row.push(cell.text.clone());
+        }
+    }
+    fill_spanned_cells(&mut row, spans);
+
+    row
+}
+
+// Repeats cells from rows above that still span the next column.
+fn fill_spanned_cells(row: &mut Vec<String>, spans: &mut [(String, usize)]) {
+    while let Some((text, remaining)) = spans.get_mut(row.len()) {
+        if *remaining == 0 {
+            break;
+        }
+
+        *remaining -= 1;
+        row.push(text.clone());
+    }
+}
+
+fn get_span(style: &str, attribute: &str) -> usize {
+    style
+        .split(&format!("{attribute}="))
+        .nth(1)
+        .map(|rest| {
+            rest.trim_start_matches(['"', '\''])
+                .chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect::<String>()
+        })
+        .and_then(|span| span.parse().ok())
+        .unwrap_or(1)
+}
+
+// Cells such as "{{flag|United States}}", "[[Germany]]", or "Tokyo, Japan".
+fn get_country_code(cell: &str) -> Option<String> {
+    let text = clean_wikitext(cell);
+    let text = text.trim_end_matches('.');
+    if let Some(region_code) = get_code_from_name(text) {
+        return Some(region_code.to_string());
+    }
+
+    text.rsplit([',', '.', '/', '('])
+        .map(|part| part.trim_matches([' ', ')']))
+        .find_map(get_code_from_name)
+        .map(|region_code| region_code.to_string())
+}
+
+fn get_link_target(cell: &str) -> Option<String> {
+    let start = cell.find("[[")? + 2;
+    let end = start + cell[start..].find("]]")?;
+    let target = cell[start..end].split('|').next()?.trim();
+    match target.is_empty() || target.contains(':') {
+        true => None,
+        false => Some(target.replace(' ', "_")),
+    }
+}
+
+// Plain text of a cell: references and tags removed, templates replaced by their country or displayed argument, and
+// links by their labels.
+fn clean_wikitext(cell: &str) -> String {
+    let mut text = cell.to_string();
+    while let Some(start) = text.find("<ref") {
+        let end = match text[start..].find('>').map(|end| start + end) {
+            Some(end) if text[..end].ends_with('/') => end + 1,
+            Some(end) => match text[end..].find("</ref>") {
+                Some(closing) => end + closing + "</ref>".len(),
+                None => text.len(),
+            },
+            None => text.len(),
+        };
+        text.replace_range(start..end, "");
+    }
+    text = text
+        .replace("<br>", ", ")
+        .replace("<br />", ", ")
+        .replace("<br/>", ", ");
+    while let Some(start) = text.find('<') {
+        match text[start..].find('>') {
+            Some(end) => text.replace_range(start..start + end + 1, ""),
+            None => break,
+        }
+    }
+
+    // Innermost templates first, so arguments are already plain text.
+    while let Some(end) = text.find("}}") {
+        let Some(start) = text[..end].rfind("{{") else {
+            break;
+        };
+
+        let arguments: Vec<&str> = text[start + 2..end].split('|').collect();
+        let name = arguments[0].trim().to_lowercase();
+        let positional: Vec<&str> = arguments[1..]
+            .iter()
+            .filter(|argument| !argument.contains('='))
+            .copied()
+            .collect();
+        let replacement = match name.starts_with("flag") {
+            true => positional.first(),
+            false => positional.last(),
+        }
+        .map(|argument| argument.trim().to_string())
+        .unwrap_or_default();
+        text.replace_range(start..end + 2, &replacement);
+    }
+
+    while let Some(start) = text.find("[[") {
+        let Some(end) = text[start..].find("]]").map(|end| start + end) else {
+            break;
+        };
+
+        let label = text[start + 2..end]
+            .rsplit('|')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        text.replace_range(start..end + 2, &label);
+    }
+
+    text.replace("'''", "")
+        .replace("''", "")
+        .replace("&nbsp;", " ")
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+async fn get_wikitext(client: &Client, title: &str) -> Result<Option<String>> {
+    let response = client
+        .get("https://en.wikipedia.org/w/api.php")
+        .query(&[
+            ("action", "query"),
+            ("prop", "revisions"),
+            ("rvprop", "content"),
+            ("rvslots", "main"),
+            ("format", "json"),
+            ("titles", title),
+        ])
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        tracing::debug!("Non-success response from Wikipedia: {}", response.status());
+        return Ok(None);
+    }
+
+    let text = response.text().await?;
+    let parsed: Value = from_str(&text)?;
+    match parsed["query"]["pages"].as_object().and_then(|pages| {
+        pages
+            .values()
+            .next()
+            .and_then(|page| page["revisions"][0]["slots"]["main"]["*"].as_str())
+    }) {
+        Some(content) => Ok(Some(content.to_string())),
+        None => {
+            tracing::error!("Failed to get content from Wikipedia response");
+            Ok(None)
         }
     }
+}
 
-    Ok(region_to_companies)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(texts: &[&str]) -> Vec<String> {
+        texts.iter().map(|text| text.to_string()).collect()
+    }
+
+    #[test]
+    fn reads_multi_row_headers() {
+        let content = "{| class=\"wikitable\"
+! rowspan=2 | Name !! colspan=2 | Headquarters
+|-
+! City !! Country
+|-
+| Acme || Springfield || United States
+|}";
+        let tables = get_tables(content);
+
+        assert_eq!(tables.len(), 1);
+        let (headers, rows) = &tables[0];
+        assert_eq!(
+            headers,
+            &vec![
+                strings(&["Name"]),
+                strings(&["Headquarters", "City"]),
+                strings(&["Headquarters", "Country"]),
+            ]
+        );
+        assert_eq!(
+            rows,
+            &vec![strings(&["Acme", "Springfield", "United States"])]
+        );
+    }
+
+    #[test]
+    fn reads_row_headers_as_data() {
+        let content = "{| class=\"wikitable\"
+! Name !! Country
+|-
+! scope=\"row\" | Acme
+| United States
+|-
+! scope=\"row\" | Globex
+| Germany
+|}";
+        let tables = get_tables(content);
+
+        assert_eq!(tables.len(), 1);
+        let (headers, rows) = &tables[0];
+        assert_eq!(headers, &vec![strings(&["Name"]), strings(&["Country"])]);
+        assert_eq!(
+            rows,
+            &vec![
+                strings(&["Acme", "United States"]),
+                strings(&["Globex", "Germany"]),
+            ]
+        );
+    }
 }
@@ -1,6 +1,8 @@
 use crate::db::util::url_exists;
 use crate::prelude::*;
-use crate::scrape::util::{get_regions, notify_parse_fail, strip_html, truncate_string};
+use crate::scrape::util::{
+    get_classification, notify_parse_fail, strip_html, truncate_string, Classification,
+};
 use crate::service::var_service::{get_youtube_api_key, get_youtube_channel_ids};
 use chrono::Local;
 use serde_json::Value;
@@ -8,7 +10,7 @@ use sqlx::SqlitePool;
 
 pub async fn scrape_youtube(
     pool: &SqlitePool,
-    media: &mut Vec<(String, String, String, Vec<String>)>,
+    media: &mut Vec<(String, String, String, Classification)>,
 ) -> Result<()> {
     let youtube_api_key = match get_youtube_api_key().await? {
         Some(api_key) => api_key,
@@ -35,8 +37,8 @@ pub async fn scrape_youtube_channel(
     pool: &SqlitePool,
     api_key: &str,
     channel_id: &str,
-) -> Result<Vec<(String, String, String, Vec<String>)>> {
-    let mut videos: Vec<(String, String, String, Vec<String>)> = Vec::new();
+) -> Result<Vec<(String, String, String, Classification)>> {
+    let mut videos: Vec<(String, String, String, Classification)> = Vec::new();
     let url =
             format!("https://www.googleapis.com/youtube/v3/search?part=snippet&maxResults=50&channelId={}&type=video&order=date&key={}",
             channel_id,
@@ -99,8 +101,8 @@ pub async fn scrape_youtube_channel(
             }
         };
 
-        let regions = get_regions(&[&title, &body]).await?;
-        videos.push((url, title, truncate_string(body)?, regions));
+        let classification = get_classification("youtube", &[&title, &body]).await?;
+        videos.push((url, title, truncate_string(body)?, classification));
     }
 
     Ok(videos)
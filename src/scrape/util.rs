@@ -1,55 +1,105 @@
-use super::region::KEYPHRASE_REGION_MAP;
-use crate::{prelude::*, service::var_service::get_docker_volume};
+use super::iso3166::get_code_from_name;
+use super::region::{
+    get_base_keyphrase, get_bloc_members, Entity, Place, KEYPHRASE_ACRONYM_MAP,
+    KEYPHRASE_ENTITY_MAP, KEYPHRASE_PLACE_MAP, KEYPHRASE_REGION_MAP, KEYPHRASE_SUBREGION_MAP,
+    TERRITORY_POLICY,
+};
+use crate::{
+    prelude::*,
+    service::var_service::{get_docker_volume, get_float_var, get_integer_var, get_optional_var},
+};
+use anyhow::anyhow;
+use once_cell::sync::Lazy;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use regex::Regex;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt::Display,
     process::Command,
     str::from_utf8,
-    sync::{Arc, Mutex},
 };
 use unidecode::unidecode;
 use url::Url;
 
-pub(super) async fn get_regions(text: &[&str]) -> Result<Vec<String>> {
-    let text = strip_content(text.join(" "))?;
-    let identified_regions = get_flashgeotext_regions(&text).await?;
-    let has_eu: bool = text.contains("EU");
-    let text = &text.replace("\\'", "'").to_lowercase(); //TODO check for correct behavior
-    let identified_regions = Arc::new(Mutex::new(identified_regions));
-    KEYPHRASE_REGION_MAP
-        .par_iter()
-        .for_each(|(keyphrases, region)| {
-            let identified_regions = Arc::clone(&identified_regions);
-            let mut identified_regions_locked = match identified_regions.lock() {
-                Ok(regions) => regions,
-                Err(poisoned) => {
-                    tracing::error!("Poisoned lock: {:?}", poisoned);
-                    return;
-                }
-            };
+pub enum RegionBasis {
+    Evidence,
+    Fallback,
+    Unknown,
+}
 
-            if identified_regions_locked.contains(region) {
-                return;
-            }
+impl RegionBasis {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RegionBasis::Evidence => "evidence",
+            RegionBasis::Fallback => "fallback",
+            RegionBasis::Unknown => "unknown",
+        }
+    }
+}
 
-            for keyphrase in keyphrases.iter() {
-                if text.contains(keyphrase) {
-                    //println!("{}: {}", keyphrase, region);
-                    identified_regions_locked.push(region);
-                    break;
-                }
-            }
-        });
+pub struct Classification {
+    pub source: &'static str,
+    pub regions: Vec<String>,
+    pub places: Vec<&'static Place>,
+    pub entities: Vec<&'static Entity>,
+    pub basis: RegionBasis,
+    pub primary_region: Option<String>,
+}
 
-    let mut regions = match identified_regions.lock() {
-        Ok(regions) => regions.clone(),
-        Err(poisoned) => {
-            tracing::error!("Poisoned lock: {:?}", poisoned);
-            Vec::new()
-        }
+pub(super) async fn get_classification(
+    source: &'static str,
+    text: &[&str],
+) -> Result<Classification> {
+    let cased_title = clean_text(text.first().unwrap_or(&""))?;
+    let title = &cased_title.to_lowercase();
+    let dateline = match text.get(1).and_then(|body| get_dateline(body)) {
+        Some(dateline) => Some(normalize_text(dateline)?),
+        None => None,
     };
+    let text = strip_content(text.join(" "))?;
+    let identified_regions = get_flashgeotext_regions(&text).await?;
+    let cased_text = &text.replace("\\'", "'");
+    let text = &cased_text.to_lowercase(); //TODO check for correct behavior
+    let weights = get_category_weights().await?;
+    let min_evidence = get_float_var("MIN_REGION_EVIDENCE", 1.0).await?;
+    let min_category_evidence = parse_category_values("MIN_CATEGORY_EVIDENCE").await?;
+    let corroborated_categories = get_corroborated_categories().await?;
+    let mut evidence: HashMap<&str, HashMap<&str, f64>> = KEYPHRASE_REGION_MAP
+        .par_iter()
+        .map(|(keyphrases, region)| (*region, get_region_evidence(keyphrases, text, &weights)))
+        .filter(|(_, region_evidence)| !region_evidence.is_empty())
+        .collect();
+    for (acronyms, region) in KEYPHRASE_ACRONYM_MAP.iter() {
+        let matches = acronyms
+            .iter()
+            .filter(|acronym| count_acronym(cased_text, acronym) > 0)
+            .count();
+        if matches > 0 {
+            *evidence
+                .entry(region)
+                .or_default()
+                .entry("acronym")
+                .or_default() += matches as f64 * get_weight(&weights, "acronym");
+        }
+    }
+
+    // Regions found by flashgeotext are named outright. Others need enough evidence, and keyphrases of corroborated
+    // categories, such as enterprises, only count once a keyphrase of an uncorroborated category agrees on the region.
+    let mut regions = identified_regions;
+    let mut evidenced_regions: Vec<&str> = evidence
+        .into_iter()
+        .filter(|(region, region_evidence)| {
+            is_evidenced(
+                region_evidence,
+                &corroborated_categories,
+                &min_category_evidence,
+                min_evidence,
+            ) && !regions.contains(region)
+        })
+        .map(|(region, _)| region)
+        .collect();
+    evidenced_regions.sort_unstable();
+    regions.extend(evidenced_regions);
 
     if text.contains("georgia") && !regions.contains(&"us") && !regions.contains(&"ge") {
         regions.push("ge");
@@ -63,11 +113,377 @@ pub(super) async fn get_regions(text: &[&str]) -> Result<Vec<String>> {
     if text.contains("sudan") && !text.contains("south sudan") && !regions.contains(&"sd") {
         regions.push("sd");
     }
-    if regions.is_empty() && !text.contains("europe") && !has_eu {
-        regions.push("us");
+    // Disputed territories matched by manual keyphrases or flashgeotext are coded like automated ones, whose places
+    // and subregions are already coded by the territory policy.
+    let mut regions = get_territory_regions(&regions);
+    // Subregions are only considered within matched countries, since many place names recur across countries.
+    let subregions: Vec<&str> = KEYPHRASE_SUBREGION_MAP
+        .par_iter()
+        .filter(|(keyphrases, subregion)| {
+            subregion
+                .split_once('-')
+                .is_some_and(|(region, _)| regions.contains(&region))
+                && keyphrases
+                    .iter()
+                    .any(|(keyphrase, _)| text.contains(keyphrase))
+        })
+        .map(|(_, subregion)| *subregion)
+        .collect();
+    regions.extend(subregions);
+    let places = get_places(text, &regions);
+    let entities = get_entities(text);
+    // Blocs listed in IMPLIED_BLOC_MEMBERS, or all of them with "all", also tag their member countries.
+    if let Some(implied_blocs) = get_optional_var("IMPLIED_BLOC_MEMBERS").await? {
+        let implied_blocs: Vec<&str> = implied_blocs.split(',').map(|s| s.trim()).collect();
+        for (bloc, members) in get_bloc_members() {
+            if !regions.contains(&bloc)
+                || !(implied_blocs.contains(&"all") || implied_blocs.contains(&bloc))
+            {
+                continue;
+            }
+
+            for member in members {
+                if !regions.contains(&member) {
+                    regions.push(member);
+                }
+            }
+        }
+    }
+    let primary_region = get_primary_region(
+        &regions,
+        (text, cased_text),
+        (title, &cased_title),
+        dateline.as_deref(),
+    )
+    .await?;
+    // Territorial subregions and bloc members are coded once they are added.
+    let mut primary_region = primary_region
+        .map(|primary_region| TERRITORY_POLICY.get_codes(&primary_region)[0].to_string());
+    let mut regions: Vec<String> = get_territory_regions(&regions)
+        .into_iter()
+        .map(|region| region.to_string())
+        .collect();
+    let basis = match regions.is_empty() {
+        false => RegionBasis::Evidence,
+        true => match get_fallback(source).await? {
+            Some(fallback) => {
+                primary_region = Some(fallback.clone());
+                regions.push(fallback);
+                RegionBasis::Fallback
+            }
+            None => RegionBasis::Unknown,
+        },
+    };
+
+    Ok(Classification {
+        source,
+        regions,
+        places,
+        entities,
+        basis,
+        primary_region,
+    })
+}
+
+fn get_territory_regions<'a>(regions: &[&'a str]) -> Vec<&'a str> {
+    let mut territory_regions = Vec::new();
+    for region in regions {
+        for code in TERRITORY_POLICY.get_codes(region) {
+            if !territory_regions.contains(&code) {
+                territory_regions.push(code);
+            }
+        }
+    }
+
+    territory_regions
+}
+
+// Sums the weights of the keyphrases matched in the text by category. Variants of one keyphrase, such as " uk " and
+// "uk.", count once.
+fn get_region_evidence<'a>(
+    keyphrases: &[(&str, &'a str)],
+    text: &str,
+    weights: &HashMap<String, f64>,
+) -> HashMap<&'a str, f64> {
+    let mut matched: HashSet<(&str, &str)> = HashSet::new();
+    let mut region_evidence: HashMap<&str, f64> = HashMap::new();
+    for (keyphrase, category) in keyphrases
+        .iter()
+        .filter(|(keyphrase, _)| text.contains(keyphrase))
+    {
+        if matched.insert((get_base_keyphrase(keyphrase), category)) {
+            *region_evidence.entry(category).or_default() += get_weight(weights, category);
+        }
+    }
+
+    region_evidence
+}
+
+// KEYPHRASE_WEIGHTS overrides the evidence weight of keyphrase categories, such as "enterprise=0.5,demonym=1".
+async fn get_category_weights() -> Result<HashMap<String, f64>> {
+    let mut weights = HashMap::from([
+        ("enterprise".to_string(), 0.5),
+        ("billionaire".to_string(), 0.5),
+        ("sanctioned".to_string(), 0.5),
+    ]);
+    weights.extend(parse_category_values("KEYPHRASE_WEIGHTS").await?);
+
+    Ok(weights)
+}
+
+// Reads comma-separated "category=value" pairs, such as "enterprise=0.5,demonym=1".
+async fn parse_category_values(key: &str) -> Result<HashMap<String, f64>> {
+    let mut values = HashMap::new();
+    let Some(pairs) = get_optional_var(key).await? else {
+        return Ok(values);
+    };
+
+    for pair in pairs.split(',').filter(|pair| !pair.trim().is_empty()) {
+        let parsed = pair
+            .split_once('=')
+            .and_then(|(category, value)| Some((category, value.trim().parse().ok()?)));
+        match parsed {
+            Some((category, value)) => {
+                values.insert(category.trim().to_lowercase(), value);
+            }
+            None => {
+                let err = format!("Failed to parse {key} entry: {pair}");
+                tracing::error!(err);
+                return Err(anyhow!(err));
+            }
+        }
+    }
+
+    Ok(values)
+}
+
+// Sums the evidence of the categories that reach their MIN_CATEGORY_EVIDENCE, leaving out corroborated categories
+// unless an uncorroborated category also counts.
+fn is_evidenced(
+    region_evidence: &HashMap<&str, f64>,
+    corroborated_categories: &[String],
+    min_category_evidence: &HashMap<String, f64>,
+    min_evidence: f64,
+) -> bool {
+    let is_corroborated = |category: &str| {
+        corroborated_categories
+            .iter()
+            .any(|corroborated_category| corroborated_category == category)
+    };
+    let counted: Vec<(&str, f64)> = region_evidence
+        .iter()
+        .filter(|(category, weight)| {
+            **weight
+                >= min_category_evidence
+                    .get(**category)
+                    .copied()
+                    .unwrap_or(0.0)
+        })
+        .map(|(category, weight)| (*category, *weight))
+        .collect();
+    let corroborated = counted
+        .iter()
+        .any(|(category, _)| !is_corroborated(category));
+    let total: f64 = counted
+        .iter()
+        .filter(|(category, _)| corroborated || !is_corroborated(category))
+        .map(|(_, weight)| weight)
+        .sum();
+
+    total >= min_evidence
+}
+
+// Categories listed in CORROBORATED_CATEGORIES only count toward a region along with an unlisted category.
+async fn get_corroborated_categories() -> Result<Vec<String>> {
+    Ok(get_optional_var("CORROBORATED_CATEGORIES")
+        .await?
+        .unwrap_or("enterprise,billionaire,sanctioned".to_string())
+        .split(',')
+        .map(|category| category.trim().to_lowercase())
+        .filter(|category| !category.is_empty())
+        .collect())
+}
+
+fn get_weight(weights: &HashMap<String, f64>, category: &str) -> f64 {
+    weights.get(category).copied().unwrap_or(1.0)
+}
+
+// Scores each country or bloc by its keyphrase mentions, weighting mentions in the title by TITLE_WEIGHT and
+// a mention in the dateline by DATELINE_WEIGHT.
+async fn get_primary_region(
+    regions: &[&str],
+    (text, cased_text): (&str, &str),
+    (title, cased_title): (&str, &str),
+    dateline: Option<&str>,
+) -> Result<Option<String>> {
+    let title_weight = get_integer_var("TITLE_WEIGHT", 3).await?;
+    let dateline_weight = get_integer_var("DATELINE_WEIGHT", 10).await?;
+    let mut scores: Vec<(&str, i64)> = Vec::new();
+    for &region in regions.iter().filter(|region| !region.contains('-')) {
+        let keyphrases = KEYPHRASE_REGION_MAP
+            .iter()
+            .find(|(_, keyphrase_region)| *keyphrase_region == region)
+            .map(|(keyphrases, _)| keyphrases.as_slice())
+            .unwrap_or_default();
+        let mut score = 0;
+        for (keyphrase, _) in keyphrases {
+            score += text.matches(keyphrase).count() as i64;
+            score += title.matches(keyphrase).count() as i64 * (title_weight - 1);
+            if dateline.is_some_and(|dateline| dateline.contains(keyphrase)) {
+                score += dateline_weight;
+            }
+        }
+        let acronyms = KEYPHRASE_ACRONYM_MAP
+            .iter()
+            .find(|(_, acronym_region)| *acronym_region == region)
+            .map(|(acronyms, _)| acronyms.as_slice())
+            .unwrap_or_default();
+        for acronym in acronyms {
+            score += count_acronym(cased_text, acronym) as i64;
+            score += count_acronym(cased_title, acronym) as i64 * (title_weight - 1);
+        }
+
+        scores.push((region, score));
+    }
+
+    Ok(pick_primary_region(&scores).map(|region| region.to_string()))
+}
+
+// The highest-scoring region, with ties going to the alphabetically first region code. When no region scores, such as
+// one named only through flashgeotext, the first region is the primary one, as flashgeotext regions come first.
+fn pick_primary_region<'a>(scores: &[(&'a str, i64)]) -> Option<&'a str> {
+    let mut primary_region: Option<(&str, i64)> = None;
+    for &(region, score) in scores {
+        let is_primary = match primary_region {
+            Some((primary, primary_score)) => {
+                score > primary_score || (score == primary_score && region < primary)
+            }
+            None => score > 0,
+        };
+        if is_primary {
+            primary_region = Some((region, score));
+        }
     }
 
-    Ok(regions.iter().map(|s| s.to_string()).collect())
+    primary_region
+        .or(scores.first().copied())
+        .map(|(region, _)| region)
+}
+
+// Datelines open a body with an uppercase location, such as "GAZA CITY —" or "KYIV, Ukraine (AP) -".
+static DATELINE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^\s*([A-Z][A-Z.'\- ]+[A-Z.](?:, [A-Z][A-Za-z.'\- ]+)?)\s*(?:\([A-Za-z ]+\)\s*)?[—–-]",
+    )
+    .unwrap()
+});
+
+fn get_dateline(body: &str) -> Option<&str> {
+    DATELINE
+        .captures(body)
+        .and_then(|captures| captures.get(1))
+        .map(|dateline| dateline.as_str())
+}
+
+// Counts whole-word, case-sensitive occurrences, so that "US" matches neither "us" nor "USB".
+// Matches within runs of all-caps words, such as "CONTACT US" in a heading, are not acronyms.
+fn count_acronym(text: &str, acronym: &str) -> usize {
+    text.match_indices(acronym)
+        .filter(|(i, _)| {
+            let (before, after) = (&text[..*i], &text[i + acronym.len()..]);
+            let previous_word = before.strip_suffix(' ').map(|before| {
+                before
+                    .trim_end_matches(' ')
+                    .rsplit(|c: char| !c.is_alphabetic())
+                    .next()
+                    .unwrap_or_default()
+            });
+            let next_word = after.strip_prefix(' ').map(|after| {
+                after
+                    .trim_start_matches(' ')
+                    .split(|c: char| !c.is_alphabetic())
+                    .next()
+                    .unwrap_or_default()
+            });
+            !before
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_alphanumeric())
+                && !after.chars().next().is_some_and(|c| c.is_alphanumeric())
+                && !previous_word.is_some_and(is_caps_word)
+                && !next_word.is_some_and(is_caps_word)
+        })
+        .count()
+}
+
+fn is_caps_word(word: &str) -> bool {
+    word.chars().count() >= 2 && word.chars().all(|c| c.is_uppercase())
+}
+
+fn clean_text(text: &str) -> Result<String> {
+    Ok(strip_content(text)?.replace("\\'", "'"))
+}
+
+fn normalize_text(text: &str) -> Result<String> {
+    Ok(clean_text(text)?.to_lowercase())
+}
+
+// <SOURCE>_FALLBACK, else DEFAULT_FALLBACK, else "us" is assigned to media without evidence of a region.
+// "unknown" leaves such media unassigned.
+async fn get_fallback(source: &str) -> Result<Option<String>> {
+    let fallback = match get_optional_var(&format!("{}_FALLBACK", source.to_uppercase())).await? {
+        Some(fallback) => fallback,
+        None => get_optional_var("DEFAULT_FALLBACK")
+            .await?
+            .unwrap_or("us".to_string()),
+    };
+    match fallback.trim().to_lowercase().as_str() {
+        "unknown" => Ok(None),
+        fallback => Ok(Some(fallback.to_string())),
+    }
+}
+
+// Places are only matched within the countries found, and names that are shared by several places or
+// contained in the name of another matched place are dropped.
+fn get_places(text: &str, regions: &[&str]) -> Vec<&'static Place> {
+    let matched: Vec<&'static (&'static str, Place)> = KEYPHRASE_PLACE_MAP
+        .par_iter()
+        .filter(|(keyphrase, place)| regions.contains(&place.region) && text.contains(keyphrase))
+        .collect();
+
+    let mut places: Vec<&'static Place> = Vec::new();
+    for (keyphrase, place) in &matched {
+        let is_ambiguous = matched.iter().any(|(other_keyphrase, other_place)| {
+            other_place.geoname_id != place.geoname_id && other_keyphrase.contains(keyphrase)
+        });
+        if !is_ambiguous && !places.iter().any(|p| p.geoname_id == place.geoname_id) {
+            places.push(place);
+        }
+    }
+
+    places
+}
+
+// Entities are matched anywhere in the text, dropping those whose keyphrase is part of another matched entity's.
+fn get_entities(text: &str) -> Vec<&'static Entity> {
+    let matched: Vec<&'static (&'static str, Entity)> = KEYPHRASE_ENTITY_MAP
+        .par_iter()
+        .filter(|(keyphrase, _)| text.contains(keyphrase))
+        .collect();
+
+    let mut entities: Vec<&'static Entity> = Vec::new();
+    for (keyphrase, entity) in &matched {
+        let is_ambiguous = matched.iter().any(|(other_keyphrase, other_entity)| {
+            other_entity.entity_id != entity.entity_id
+                && other_keyphrase != keyphrase
+                && other_keyphrase.contains(keyphrase)
+        });
+        if !is_ambiguous && !entities.iter().any(|e| e.entity_id == entity.entity_id) {
+            entities.push(entity);
+        }
+    }
+
+    entities
 }
 
 pub fn notify_parse_fail<T: Display>(msg: &str, item: T) {
@@ -174,520 +590,111 @@ async fn get_flashgeotext_regions(text: &str) -> Result<Vec<&'static str>> {
                 "Chad" | "Georgia" | "Guinea-Bissau" | "Jordan" | "Republic of Congo"
             )
         })
-        .filter_map(|s| get_iso_from_name(s))
+        .filter_map(get_code_from_name)
         .collect();
 
     Ok(regions)
 }
 
-pub fn get_iso_from_name(name: &str) -> Option<&str> {
-    match name {
-        "Andorra" => Some("ad"),
-        "United Arab Emirates" => Some("ae"),
-        "Afghanistan" => Some("af"),
-        "Antigua and Barbuda" => Some("ag"),
-        "Anguilla" => Some("ai"),
-        "Albania" => Some("al"),
-        "Armenia" => Some("am"),
-        "Angola" => Some("ao"),
-        "Antarctica" => Some("aq"),
-        "Argentina" => Some("ar"),
-        "American Samoa" => Some("as"),
-        "Austria" => Some("at"),
-        "Australia" => Some("au"),
-        "Aruba" => Some("aw"),
-        "Aland Islands" => Some("ax"),
-        "Azerbaijan" => Some("az"),
-        "Bosnia and Herzegovina" => Some("ba"),
-        "Barbados" => Some("bb"),
-        "Bangladesh" => Some("bd"),
-        "Belgium" => Some("be"),
-        "Burkina Faso" => Some("bf"),
-        "Bulgaria" => Some("bg"),
-        "Bahrain" => Some("bh"),
-        "Burundi" => Some("bi"),
-        "Benin" => Some("bj"),
-        "Saint Barthelemy" => Some("bl"),
-        "Bermuda" => Some("bm"),
-        "Brunei" => Some("bn"),
-        "Bolivia" => Some("bo"),
-        "Bonaire, Sint Eustatius, and Saba" => Some("bq"),
-        "Brazil" => Some("br"),
-        "Bahamas" => Some("bs"),
-        "Bhutan" => Some("bt"),
-        "Bouvet Island" => Some("bv"),
-        "Botswana" => Some("bw"),
-        "Belarus" => Some("by"),
-        "Belize" => Some("bz"),
-        "Canada" => Some("ca"),
-        "Cocos (Keeling) Islands" => Some("cc"),
-        "Democratic Republic of the Congo" => Some("cd"),
-        "Central African Republic" => Some("cf"),
-        "Republic of the Congo" => Some("cg"),
-        "Switzerland" => Some("ch"),
-        "Ivory Coast" => Some("ci"),
-        "Cook Islands" => Some("ck"),
-        "Chile" => Some("cl"),
-        "Cameroon" => Some("cm"),
-        "China" => Some("cn"),
-        "Colombia" => Some("co"),
-        "Costa Rica" => Some("cr"),
-        "Cuba" => Some("cu"),
-        "Cape Verde" => Some("cv"),
-        "Curacao" => Some("cw"),
-        "Christmas Island" => Some("cx"),
-        "Cyprus" => Some("cy"),
-        "Czech Republic" => Some("cz"),
-        "Germany" => Some("de"),
-        "Djibouti" => Some("dj"),
-        "Denmark" => Some("dk"),
-        "Dominica" => Some("dm"),
-        "Dominican Republic" => Some("do"),
-        "Algeria" => Some("dz"),
-        "Ecuador" => Some("ec"),
-        "Estonia" => Some("ee"),
-        "Egypt" => Some("eg"),
-        "Western Sahara" => Some("eh"),
-        "Eritrea" => Some("er"),
-        "Spain" => Some("es"),
-        "Ethiopia" => Some("et"),
-        "Finland" => Some("fi"),
-        "Fiji" => Some("fj"),
-        "Falkland Islands" => Some("fk"),
-        "Micronesia" => Some("fm"),
-        "Faroe Islands" => Some("fo"),
-        "France" => Some("fr"),
-        "Gabon" => Some("ga"),
-        "United Kingdom" => Some("gb"),
-        "Grenada" => Some("gd"),
-        "Georgia" => Some("ge"),
-        "French Guiana" => Some("gf"),
-        "Guernsey" => Some("gg"),
-        "Ghana" => Some("gh"),
-        "Gibraltar" => Some("gi"),
-        "Greenland" => Some("gl"),
-        "Gambia" => Some("gm"),
-        "Guinea" => Some("gn"),
-        "Guadeloupe" => Some("gp"),
-        "Equatorial Guinea" => Some("gq"),
-        "Greece" => Some("gr"),
-        "South Georgia and the South Sandwich Islands" => Some("gs"),
-        "Guatemala" => Some("gt"),
-        "Guam" => Some("gu"),
-        "Guinea-Bissau" => Some("gw"),
-        "Guyana" => Some("gy"),
-        "Hong Kong" => Some("hk"),
-        "Heard Island and McDonald Islands" => Some("hm"),
-        "Honduras" => Some("hn"),
-        "Croatia" => Some("hr"),
-        "Haiti" => Some("ht"),
-        "Hungary" => Some("hu"),
-        "Indonesia" => Some("id"),
-        "Ireland" => Some("ie"),
-        "Israel" => Some("il"),
-        "Isle of Man" => Some("im"),
-        "India" => Some("in"),
-        "British Indian Ocean Territory" => Some("io"),
-        "Iraq" => Some("iq"),
-        "Iran" => Some("ir"),
-        "Iceland" => Some("is"),
-        "Italy" => Some("it"),
-        "Jersey" => Some("je"),
-        "Jamaica" => Some("jm"),
-        "Jordan" => Some("jo"),
-        "Japan" => Some("jp"),
-        "Kenya" => Some("ke"),
-        "Kyrgyzstan" => Some("kg"),
-        "Cambodia" => Some("kh"),
-        "Kiribati" => Some("ki"),
-        "Comoros" => Some("km"),
-        "Saint Kitts and Nevis" => Some("kn"),
-        "North Korea" => Some("kp"),
-        "South Korea" => Some("kr"),
-        "Kuwait" => Some("kw"),
-        "Cayman Islands" => Some("ky"),
-        "Kazakhstan" => Some("kz"),
-        "Laos" => Some("la"),
-        "Lebanon" => Some("lb"),
-        "Saint Lucia" => Some("lc"),
-        "Liechtenstein" => Some("li"),
-        "Sri Lanka" => Some("lk"),
-        "Liberia" => Some("lr"),
-        "Lesotho" => Some("ls"),
-        "Lithuania" => Some("lt"),
-        "Luxembourg" => Some("lu"),
-        "Latvia" => Some("lv"),
-        "Libya" => Some("ly"),
-        "Morocco" => Some("ma"),
-        "Monaco" => Some("mc"),
-        "Moldova" => Some("md"),
-        "Montenegro" => Some("me"),
-        "Saint Martin" => Some("mf"),
-        "Madagascar" => Some("mg"),
-        "Marshall Islands" => Some("mh"),
-        "North Macedonia" => Some("mk"),
-        "Mali" => Some("ml"),
-        "Myanmar" => Some("mm"),
-        "Mongolia" => Some("mn"),
-        "Macau" => Some("mo"),
-        "Northern Mariana Islands" => Some("mp"),
-        "Martinique" => Some("mq"),
-        "Mauritania" => Some("mr"),
-        "Montserrat" => Some("ms"),
-        "Malta" => Some("mt"),
-        "Mauritius" => Some("mu"),
-        "Maldives" => Some("mv"),
-        "Malawi" => Some("mw"),
-        "Mexico" => Some("mx"),
-        "Malaysia" => Some("my"),
-        "Mozambique" => Some("mz"),
-        "Namibia" => Some("na"),
-        "New Caledonia" => Some("nc"),
-        "Niger" => Some("ne"),
-        "Norfolk Island" => Some("nf"),
-        "Nigeria" => Some("ng"),
-        "Nicaragua" => Some("ni"),
-        "Netherlands" => Some("nl"),
-        "Norway" => Some("no"),
-        "Nepal" => Some("np"),
-        "Nauru" => Some("nr"),
-        "Niue" => Some("nu"),
-        "New Zealand" => Some("nz"),
-        "Oman" => Some("om"),
-        "Panama" => Some("pa"),
-        "Peru" => Some("pe"),
-        "French Polynesia" => Some("pf"),
-        "Papua New Guinea" => Some("pg"),
-        "Philippines" => Some("ph"),
-        "Pakistan" => Some("pk"),
-        "Poland" => Some("pl"),
-        "Saint Pierre and Miquelon" => Some("pm"),
-        "Pitcairn Islands" => Some("pn"),
-        "Puerto Rico" => Some("pr"),
-        "Palestine" => Some("ps"),
-        "Portugal" => Some("pt"),
-        "Palau" => Some("pw"),
-        "Paraguay" => Some("py"),
-        "Qatar" => Some("qa"),
-        "Reunion" => Some("re"),
-        "Romania" => Some("ro"),
-        "Serbia" => Some("rs"),
-        "Russia" => Some("ru"),
-        "Rwanda" => Some("rw"),
-        "Saudi Arabia" => Some("sa"),
-        "Solomon Islands" => Some("sb"),
-        "Seychelles" => Some("sc"),
-        "Sudan" => Some("sd"),
-        "Sweden" => Some("se"),
-        "Singapore" => Some("sg"),
-        "Saint Helena" => Some("sh"),
-        "Slovenia" => Some("si"),
-        "Svalbard and Jan Mayen" => Some("sj"),
-        "Slovakia" => Some("sk"),
-        "Sierra Leone" => Some("sl"),
-        "San Marino" => Some("sm"),
-        "Senegal" => Some("sn"),
-        "Somalia" => Some("so"),
-        "Suriname" => Some("sr"),
-        "South Sudan" => Some("ss"),
-        "Sao Tome and Principe" => Some("st"),
-        "El Salvador" => Some("sv"),
-        "Sint Maarten" => Some("sx"),
-        "Syria" => Some("sy"),
-        "Eswatini" => Some("sz"),
-        "Turks and Caicos Islands" => Some("tc"),
-        "Chad" => Some("td"),
-        "French Southern Territories" => Some("tf"),
-        "Togo" => Some("tg"),
-        "Thailand" => Some("th"),
-        "Tajikistan" => Some("tj"),
-        "Tokelau" => Some("tk"),
-        "East Timor" => Some("tl"),
-        "Turkmenistan" => Some("tm"),
-        "Tunisia" => Some("tn"),
-        "Tonga" => Some("to"),
-        "Turkey" => Some("tr"),
-        "Trinidad and Tobago" => Some("tt"),
-        "Tuvalu" => Some("tv"),
-        "Taiwan" => Some("tw"),
-        "Tanzania" => Some("tz"),
-        "Ukraine" => Some("ua"),
-        "Uganda" => Some("ug"),
-        "United States Minor Outlying Islands" => Some("um"),
-        "United States" => Some("us"),
-        "Uruguay" => Some("uy"),
-        "Uzbekistan" => Some("uz"),
-        "Vatican City" => Some("va"),
-        "Saint Vincent and the Grenadines" => Some("vc"),
-        "Venezuela" => Some("ve"),
-        "British Virgin Islands" => Some("vg"),
-        "United States Virgin Islands" => Some("vi"),
-        "Vietnam" => Some("vn"),
-        "Vanuatu" => Some("vu"),
-        "Wallis and Futuna" => Some("wf"),
-        "Samoa" => Some("ws"),
-        "Kosovo" => Some("xk"),
-        "Yemen" => Some("ye"),
-        "Mayotte" => Some("yt"),
-        "South Africa" => Some("za"),
-        "Zambia" => Some("zm"),
-        "Zimbabwe" => Some("zw"),
-        _ => None,
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_acronyms_in_all_caps_runs() {
+        assert_eq!(count_acronym("The US and the UAE signed it.", "US"), 1);
+        assert_eq!(
+            count_acronym("Talks in the U.S., US officials say", "US"),
+            1
+        );
+        assert_eq!(count_acronym("JOIN US", "US"), 0);
+        assert_eq!(count_acronym("CONTACT US | About", "US"), 0);
+        assert_eq!(count_acronym("Sanctions: US, UAE", "US"), 1);
+        assert_eq!(count_acronym("USA", "US"), 0);
     }
-}
 
-// pub fn get_name_from_iso(iso_code: &str) -> Option<&str> {
-//     match iso_code {
-//         "ad" => Some("Andorra"),
-//         "ae" => Some("United Arab Emirates"),
-//         "af" => Some("Afghanistan"),
-//         "ag" => Some("Antigua and Barbuda"),
-//         "ai" => Some("Anguilla"),
-//         "al" => Some("Albania"),
-//         "am" => Some("Armenia"),
-//         "ao" => Some("Angola"),
-//         "aq" => Some("Antarctica"),
-//         "ar" => Some("Argentina"),
-//         "as" => Some("American Samoa"),
-//         "at" => Some("Austria"),
-//         "au" => Some("Australia"),
-//         "aw" => Some("Aruba"),
-//         "ax" => Some("Aland Islands"),
-//         "az" => Some("Azerbaijan"),
-//         "ba" => Some("Bosnia and Herzegovina"),
-//         "bb" => Some("Barbados"),
-//         "bd" => Some("Bangladesh"),
-//         "be" => Some("Belgium"),
-//         "bf" => Some("Burkina Faso"),
-//         "bg" => Some("Bulgaria"),
-//         "bh" => Some("Bahrain"),
-//         "bi" => Some("Burundi"),
-//         "bj" => Some("Benin"),
-//         "bl" => Some("Saint Barthelemy"),
-//         "bm" => Some("Bermuda"),
-//         "bn" => Some("Brunei"),
-//         "bo" => Some("Bolivia"),
-//         "bq" => Some("Bonaire, Sint Eustatius, and Saba"),
-//         "br" => Some("Brazil"),
-//         "bs" => Some("Bahamas"),
-//         "bt" => Some("Bhutan"),
-//         "bv" => Some("Bouvet Island"),
-//         "bw" => Some("Botswana"),
-//         "by" => Some("Belarus"),
-//         "bz" => Some("Belize"),
-//         "ca" => Some("Canada"),
-//         "cc" => Some("Cocos (Keeling) Islands"),
-//         "cd" => Some("Democratic Republic of the Congo"),
-//         "cf" => Some("Central African Republic"),
-//         "cg" => Some("Republic of the Congo"),
-//         "ch" => Some("Switzerland"),
-//         "ci" => Some("Ivory Coast"),
-//         "ck" => Some("Cook Islands"),
-//         "cl" => Some("Chile"),
-//         "cm" => Some("Cameroon"),
-//         "cn" => Some("China"),
-//         "co" => Some("Colombia"),
-//         "cr" => Some("Costa Rica"),
-//         "cu" => Some("Cuba"),
-//         "cv" => Some("Cape Verde"),
-//         "cw" => Some("Curacao"),
-//         "cx" => Some("Christmas Island"),
-//         "cy" => Some("Cyprus"),
-//         "cz" => Some("Czech Republic"),
-//         "de" => Some("Germany"),
-//         "dj" => Some("Djibouti"),
-//         "dk" => Some("Denmark"),
-//         "dm" => Some("Dominica"),
-//         "do" => Some("Dominican Republic"),
-//         "dz" => Some("Algeria"),
-//         "ec" => Some("Ecuador"),
-//         "ee" => Some("Estonia"),
-//         "eg" => Some("Egypt"),
-//         "eh" => Some("Western Sahara"),
-//         "er" => Some("Eritrea"),
-//         "es" => Some("Spain"),
-//         "et" => Some("Ethiopia"),
-//         "fi" => Some("Finland"),
-//         "fj" => Some("Fiji"),
-//         "fk" => Some("Falkland Islands"),
-//         "fm" => Some("Micronesia"),
-//         "fo" => Some("Faroe Islands"),
-//         "fr" => Some("France"),
-//         "ga" => Some("Gabon"),
-//         "gb" => Some("United Kingdom"),
-//         "gd" => Some("Grenada"),
-//         "ge" => Some("Georgia"),
-//         "gf" => Some("French Guiana"),
-//         "gg" => Some("Guernsey"),
-//         "gh" => Some("Ghana"),
-//         "gi" => Some("Gibraltar"),
-//         "gl" => Some("Greenland"),
-//         "gm" => Some("Gambia"),
-//         "gn" => Some("Guinea"),
-//         "gp" => Some("Guadeloupe"),
-//         "gq" => Some("Equatorial Guinea"),
-//         "gr" => Some("Greece"),
-//         "gs" => Some("South Georgia and the South Sandwich Islands"),
-//         "gt" => Some("Guatemala"),
-//         "gu" => Some("Guam"),
-//         "gw" => Some("Guinea-Bissau"),
-//         "gy" => Some("Guyana"),
-//         "hk" => Some("Hong Kong"),
-//         "hm" => Some("Heard Island and McDonald Islands"),
-//         "hn" => Some("Honduras"),
-//         "hr" => Some("Croatia"),
-//         "ht" => Some("Haiti"),
-//         "hu" => Some("Hungary"),
-//         "id" => Some("Indonesia"),
-//         "ie" => Some("Ireland"),
-//         "il" => Some("Israel"),
-//         "im" => Some("Isle of Man"),
-//         "in" => Some("India"),
-//         "io" => Some("British Indian Ocean Territory"),
-//         "iq" => Some("Iraq"),
-//         "ir" => Some("Iran"),
-//         "is" => Some("Iceland"),
-//         "it" => Some("Italy"),
-//         "je" => Some("Jersey"),
-//         "jm" => Some("Jamaica"),
-//         "jo" => Some("Jordan"),
-//         "jp" => Some("Japan"),
-//         "ke" => Some("Kenya"),
-//         "kg" => Some("Kyrgyzstan"),
-//         "kh" => Some("Cambodia"),
-//         "ki" => Some("Kiribati"),
-//         "km" => Some("Comoros"),
-//         "kn" => Some("Saint Kitts and Nevis"),
-//         "kp" => Some("North Korea"),
-//         "kr" => Some("South Korea"),
-//         "kw" => Some("Kuwait"),
-//         "ky" => Some("Cayman Islands"),
-//         "kz" => Some("Kazakhstan"),
-//         "la" => Some("Laos"),
-//         "lb" => Some("Lebanon"),
-//         "lc" => Some("Saint Lucia"),
-//         "li" => Some("Liechtenstein"),
-//         "lk" => Some("Sri Lanka"),
-//         "lr" => Some("Liberia"),
-//         "ls" => Some("Lesotho"),
-//         "lt" => Some("Lithuania"),
-//         "lu" => Some("Luxembourg"),
-//         "lv" => Some("Latvia"),
-//         "ly" => Some("Libya"),
-//         "ma" => Some("Morocco"),
-//         "mc" => Some("Monaco"),
-//         "md" => Some("Moldova"),
-//         "me" => Some("Montenegro"),
-//         "mf" => Some("Saint Martin"),
-//         "mg" => Some("Madagascar"),
-//         "mh" => Some("Marshall Islands"),
-//         "mk" => Some("North Macedonia"),
-//         "ml" => Some("Mali"),
-//         "mm" => Some("Myanmar"),
-//         "mn" => Some("Mongolia"),
-//         "mo" => Some("Macau"),
-//         "mp" => Some("Northern Mariana Islands"),
-//         "mq" => Some("Martinique"),
-//         "mr" => Some("Mauritania"),
-//         "ms" => Some("Montserrat"),
-//         "mt" => Some("Malta"),
-//         "mu" => Some("Mauritius"),
-//         "mv" => Some("Maldives"),
-//         "mw" => Some("Malawi"),
-//         "mx" => Some("Mexico"),
-//         "my" => Some("Malaysia"),
-//         "mz" => Some("Mozambique"),
-//         "na" => Some("Namibia"),
-//         "nc" => Some("New Caledonia"),
-//         "ne" => Some("Niger"),
-//         "nf" => Some("Norfolk Island"),
-//         "ng" => Some("Nigeria"),
-//         "ni" => Some("Nicaragua"),
-//         "nl" => Some("Netherlands"),
-//         "no" => Some("Norway"),
-//         "np" => Some("Nepal"),
-//         "nr" => Some("Nauru"),
-//         "nu" => Some("Niue"),
-//         "nz" => Some("New Zealand"),
-//         "om" => Some("Oman"),
-//         "pa" => Some("Panama"),
-//         "pe" => Some("Peru"),
-//         "pf" => Some("French Polynesia"),
-//         "pg" => Some("Papua New Guinea"),
-//         "ph" => Some("Philippines"),
-//         "pk" => Some("Pakistan"),
-//         "pl" => Some("Poland"),
-//         "pm" => Some("Saint Pierre and Miquelon"),
-//         "pn" => Some("Pitcairn Islands"),
-//         "pr" => Some("Puerto Rico"),
-//         "ps" => Some("Palestine"),
-//         "pt" => Some("Portugal"),
-//         "pw" => Some("Palau"),
-//         "py" => Some("Paraguay"),
-//         "qa" => Some("Qatar"),
-//         "re" => Some("Reunion"),
-//         "ro" => Some("Romania"),
-//         "rs" => Some("Serbia"),
-//         "ru" => Some("Russia"),
-//         "rw" => Some("Rwanda"),
-//         "sa" => Some("Saudi Arabia"),
-//         "sb" => Some("Solomon Islands"),
-//         "sc" => Some("Seychelles"),
-//         "sd" => Some("Sudan"),
-//         "se" => Some("Sweden"),
-//         "sg" => Some("Singapore"),
-//         "sh" => Some("Saint Helena"),
-//         "si" => Some("Slovenia"),
-//         "sj" => Some("Svalbard and Jan Mayen"),
-//         "sk" => Some("Slovakia"),
-//         "sl" => Some("Sierra Leone"),
-//         "sm" => Some("San Marino"),
-//         "sn" => Some("Senegal"),
-//         "so" => Some("Somalia"),
-//         "sr" => Some("Suriname"),
-//         "ss" => Some("South Sudan"),
-//         "st" => Some("Sao Tome and Principe"),
-//         "sv" => Some("El Salvador"),
-//         "sx" => Some("Sint Maarten"),
-//         "sy" => Some("Syria"),
-//         "sz" => Some("Eswatini"),
-//         "tc" => Some("Turks and Caicos Islands"),
-//         "td" => Some("Chad"),
-//         "tf" => Some("French Southern Territories"),
-//         "tg" => Some("Togo"),
-//         "th" => Some("Thailand"),
-//         "tj" => Some("Tajikistan"),
-//         "tk" => Some("Tokelau"),
-//         "tl" => Some("East Timor"),
-//         "tm" => Some("Turkmenistan"),
-//         "tn" => Some("Tunisia"),
-//         "to" => Some("Tonga"),
-//         "tr" => Some("Turkey"),
-//         "tt" => Some("Trinidad and Tobago"),
-//         "tv" => Some("Tuvalu"),
-//         "tw" => Some("Taiwan"),
-//         "tz" => Some("Tanzania"),
-//         "ua" => Some("Ukraine"),
-//         "ug" => Some("Uganda"),
-//         "um" => Some("United States Minor Outlying Islands"),
-//         "us" => Some("United States"),
-//         "uy" => Some("Uruguay"),
-//         "uz" => Some("Uzbekistan"),
-//         "va" => Some("Vatican City"),
-//         "vc" => Some("Saint Vincent and the Grenadines"),
-//         "ve" => Some("Venezuela"),
-//         "vg" => Some("British Virgin Islands"),
-//         "vi" => Some("United States Virgin Islands"),
-//         "vn" => Some("Vietnam"),
-//         "vu" => Some("Vanuatu"),
-//         "wf" => Some("Wallis and Futuna"),
-//         "ws" => Some("Samoa"),
-//         "xk" => Some("Kosovo"),
-//         "ye" => Some("Yemen"),
-//         "yt" => Some("Mayotte"),
-//         "za" => Some("South Africa"),
-//         "zm" => Some("Zambia"),
-//         "zw" => Some("Zimbabwe"),
-//         _ => None,
-//     }
-// }
+    #[test]
+    fn picks_primary_region() {
+        assert_eq!(
+            pick_primary_region(&[("fr", 2), ("de", 5), ("ua", 5)]),
+            Some("de")
+        );
+        // Regions that no keyphrase scores, such as those named by flashgeotext, still give a primary region.
+        assert_eq!(pick_primary_region(&[("ua", 0), ("de", 0)]), Some("ua"));
+        assert_eq!(pick_primary_region(&[("ua", 0), ("de", 1)]), Some("de"));
+        assert_eq!(pick_primary_region(&[]), None);
+    }
+
+    #[test]
+    fn requires_corroboration_for_corroborated_categories() {
+        let corroborated = vec![
+            "enterprise".to_string(),
+            "billionaire".to_string(),
+            "sanctioned".to_string(),
+        ];
+        let is_evidenced_by = |categories: &[(&'static str, f64)]| {
+            is_evidenced(
+                &HashMap::from_iter(categories.iter().copied()),
+                &corroborated,
+                &HashMap::new(),
+                1.0,
+            )
+        };
+
+        assert!(is_evidenced_by(&[("place", 1.0)]));
+        assert!(!is_evidenced_by(&[("place", 0.5)]));
+        assert!(!is_evidenced_by(&[("enterprise", 1.0)]));
+        assert!(!is_evidenced_by(&[
+            ("enterprise", 0.5),
+            ("billionaire", 0.5)
+        ]));
+        assert!(!is_evidenced_by(&[
+            ("sanctioned", 1.0),
+            ("billionaire", 1.0)
+        ]));
+        assert!(is_evidenced_by(&[("enterprise", 0.5), ("acronym", 0.5)]));
+        assert!(!is_evidenced_by(&[]));
+    }
+
+    #[test]
+    fn applies_category_minimums() {
+        let minimums = HashMap::from([("demonym".to_string(), 2.0), ("figure".to_string(), 1.0)]);
+        let is_evidenced_by = |categories: &[(&'static str, f64)]| {
+            is_evidenced(
+                &HashMap::from_iter(categories.iter().copied()),
+                &["enterprise".to_string()],
+                &minimums,
+                1.0,
+            )
+        };
+
+        assert!(!is_evidenced_by(&[("demonym", 1.0)]));
+        assert!(is_evidenced_by(&[("demonym", 2.0)]));
+        assert!(is_evidenced_by(&[("figure", 1.0)]));
+        // A category below its minimum neither counts nor corroborates.
+        assert!(!is_evidenced_by(&[("demonym", 1.0), ("enterprise", 0.5)]));
+    }
+
+    #[test]
+    fn counts_keyphrase_variants_once() {
+        let keyphrases = [
+            (" uk ", "name"),
+            ("uk.", "name"),
+            ("uk,", "name"),
+            ("london", "place"),
+            ("acme inc", "enterprise"),
+            ("acme ltd", "enterprise"),
+        ];
+        let weights = HashMap::from([("enterprise".to_string(), 0.5)]);
+        let evidence = get_region_evidence(
+            &keyphrases,
+            "the uk, said in london. the uk. acme inc and acme ltd",
+            &weights,
+        );
+
+        assert_eq!(
+            evidence,
+            HashMap::from([("name", 1.0), ("place", 1.0), ("enterprise", 0.5)])
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::db::{
-    media::update_media_db,
+    media::{update_bloc_members, update_countries, update_media_db},
     util::{create_media_db, get_db_pool},
 };
 use crate::prelude::*;
@@ -20,6 +20,8 @@ pub async fn run_scrapers(docker_volume: &str) -> Result<()> {
     let db_path = Path::new(&db_path);
     let pool = get_db_pool(db_path).await?;
     create_media_db(&pool).await?;
+    update_bloc_members(&pool).await?;
+    update_countries(&pool).await?;
     let mut media = Vec::new();
     scrape_accuracy(&pool, &mut media).await?;
     scrape_amnesty(&pool, &mut media).await?;
@@ -90,3 +90,56 @@ pub async fn get_youtube_channel_ids() -> Result<Option<String>> {
         }
     }
 }
+
+pub async fn get_integer_var(key: &str, default: i64) -> Result<i64> {
+    match var(key) {
+        Ok(value) => match value.is_empty() {
+            true => {
+                tracing::info!("{key} is empty");
+                Ok(default)
+            }
+            false => match value.parse::<i64>() {
+                Ok(value) => Ok(value),
+                Err(e) => {
+                    let err = format!("Failed to parse {key}: {e}");
+                    tracing::error!(err);
+                    Err(anyhow!(err))
+                }
+            },
+        },
+        Err(_) => Ok(default),
+    }
+}
+
+pub async fn get_float_var(key: &str, default: f64) -> Result<f64> {
+    match var(key) {
+        Ok(value) => match value.is_empty() {
+            true => {
+                tracing::info!("{key} is empty");
+                Ok(default)
+            }
+            false => match value.parse::<f64>() {
+                Ok(value) => Ok(value),
+                Err(e) => {
+                    let err = format!("Failed to parse {key}: {e}");
+                    tracing::error!(err);
+                    Err(anyhow!(err))
+                }
+            },
+        },
+        Err(_) => Ok(default),
+    }
+}
+
+pub async fn get_optional_var(key: &str) -> Result<Option<String>> {
+    match var(key) {
+        Ok(value) => match value.is_empty() {
+            true => {
+                tracing::info!("{key} is empty");
+                Ok(None)
+            }
+            false => Ok(Some(value)),
+        },
+        Err(_) => Ok(None),
+    }
+}
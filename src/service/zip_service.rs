@@ -1,36 +1,248 @@
 use crate::prelude::*;
-use async_std::io::ReadExt;
+use anyhow::anyhow;
+use async_std::io::{prelude::BufReadExt, BufReader as AsyncBufReader};
 use async_zip::tokio::read::seek::ZipFileReader;
-use reqwest::Client;
+use reqwest::{
+    header::{
+        CONTENT_RANGE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, IF_RANGE, LAST_MODIFIED, RANGE,
+    },
+    Client, Response, StatusCode,
+};
+use std::path::Path;
 use tokio::{
-    fs::File,
-    io::{AsyncWriteExt, BufReader, BufWriter},
+    fs::{read_to_string, remove_file, rename, write, File, OpenOptions},
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter},
 };
 use tokio_stream::StreamExt;
 
+// Downloads to a ".part" file first, resuming it when a previous download was interrupted. The ETag or Last-Modified
+// of the partial download is kept next to it and sent as If-Range, so the server restarts the download when the file
+// has since been replaced instead of appending the new file to the old one. That of a completed download is kept next
+// to the zip, so that an existing zip is only downloaded again once the server's file has changed.
 pub async fn zip_from_url(client: &Client, url: &str, zip_path: &str) -> Result<()> {
-    let mut zip_file = BufWriter::new(File::create(zip_path).await?);
-    let mut stream = client.get(url).send().await?.bytes_stream();
+    let part_path = format!("{}.part", zip_path);
+    let validator_path = format!("{}.validator", part_path);
+    let validator = read_to_string(&validator_path).await.ok();
+    let zip_validator_path = format!("{}.validator", zip_path);
+    let zip_validator = match Path::new(zip_path).exists() {
+        true => read_to_string(&zip_validator_path).await.ok(),
+        false => None,
+    };
+    let mut downloaded = match (Path::new(&part_path).exists(), &validator) {
+        (true, Some(_)) => File::open(&part_path).await?.metadata().await?.len(),
+        _ => 0,
+    };
+
+    let response = loop {
+        let mut request = client.get(url);
+        if let (true, Some(validator)) = (downloaded > 0, &validator) {
+            tracing::info!("Resuming download of {url} from byte {downloaded}.");
+            request = request
+                .header(RANGE, format!("bytes={}-", downloaded))
+                .header(IF_RANGE, validator.trim());
+        } else if let Some(zip_validator) = &zip_validator {
+            // ETags are quoted, while Last-Modified is a date.
+            let zip_validator = zip_validator.trim();
+            request = match zip_validator.starts_with('"') {
+                true => request.header(IF_NONE_MATCH, zip_validator),
+                false => request.header(IF_MODIFIED_SINCE, zip_validator),
+            };
+        }
+        let response = request.send().await?;
+        // A ".part" file that is already complete, such as one whose rename failed, has no bytes left to request.
+        if response.status() == StatusCode::RANGE_NOT_SATISFIABLE && downloaded > 0 {
+            tracing::info!("Cannot resume {part_path}. Restarting the download of {url}.");
+            remove_file(&part_path).await?;
+            downloaded = 0;
+            continue;
+        }
+
+        break response;
+    };
+    let expected_size = match response.status() {
+        StatusCode::NOT_MODIFIED if zip_validator.is_some() => {
+            tracing::info!("{url} is unchanged. Keeping {zip_path}.");
+            return Ok(());
+        }
+        StatusCode::PARTIAL_CONTENT => match get_content_range(&response) {
+            Some((start, total)) if start == downloaded => Some(total),
+            content_range => {
+                let err =
+                    format!("Unexpected Content-Range while resuming {url}: {content_range:?}");
+                tracing::error!(err);
+                return Err(anyhow!(err));
+            }
+        },
+        status if status.is_success() => {
+            downloaded = 0;
+            match get_validator(&response) {
+                Some(validator) => write(&validator_path, validator).await?,
+                None if Path::new(&validator_path).exists() => remove_file(&validator_path).await?,
+                None => (),
+            }
+            response.content_length()
+        }
+        status => {
+            let err = format!("Non-success response while downloading {url}: {status}");
+            tracing::error!(err);
+            return Err(anyhow!(err));
+        }
+    };
 
+    let zip_file = OpenOptions::new()
+        .create(true)
+        .append(downloaded > 0)
+        .write(true)
+        .truncate(downloaded == 0)
+        .open(&part_path)
+        .await?;
+    let mut zip_file = BufWriter::new(zip_file);
+    let mut stream = response.bytes_stream();
     while let Some(item) = stream.next().await {
-        zip_file.write_all(&item?).await?;
+        let item = item?;
+        downloaded += item.len() as u64;
+        zip_file.write_all(&item).await?;
     }
     zip_file.flush().await?;
 
+    if let Some(expected_size) = expected_size {
+        if downloaded != expected_size {
+            let err = format!("Downloaded {downloaded} of {expected_size} bytes from {url}");
+            tracing::error!(err);
+            return Err(anyhow!(err));
+        }
+    }
+
+    rename(&part_path, zip_path).await?;
+    match Path::new(&validator_path).exists() {
+        true => rename(&validator_path, &zip_validator_path).await?,
+        false if Path::new(&zip_validator_path).exists() => {
+            remove_file(&zip_validator_path).await?
+        }
+        false => (),
+    }
+
     Ok(())
 }
 
-pub async fn zip_to_txt(zip_path: &str) -> Result<()> {
-    let mut zip_reader = BufReader::new(File::open(zip_path).await?);
+// The first byte and the total size of a "bytes start-end/total" Content-Range.
+fn get_content_range(response: &Response) -> Option<(u64, u64)> {
+    let content_range = response.headers().get(CONTENT_RANGE)?.to_str().ok()?;
+    let (range, total) = content_range.strip_prefix("bytes ")?.split_once('/')?;
+    let (start, _) = range.split_once('-')?;
+    Some((start.trim().parse().ok()?, total.trim().parse().ok()?))
+}
+
+// Only strong ETags identify the exact bytes of a file, so weak ones fall back to Last-Modified.
+fn get_validator(response: &Response) -> Option<String> {
+    let header = |name| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string())
+    };
+    header(ETAG)
+        .filter(|etag| !etag.starts_with("W/"))
+        .or_else(|| header(LAST_MODIFIED))
+}
+
+// Streams the lines of a text file, or of the data entry of a zip file, without decompressing it to disk.
+// GeoNames zips also contain a readme or language codes, so the entry named after the zip is read, or else the first
+// entry that is not a readme.
+pub async fn for_each_line<F: FnMut(&str)>(path: &str, mut f: F) -> Result<()> {
+    let mut line = String::new();
+    if !path.ends_with(".zip") {
+        let mut reader = BufReader::new(File::open(path).await?);
+        while reader.read_line(&mut line).await? > 0 {
+            f(line.trim_end_matches(['\r', '\n']));
+            line.clear();
+        }
+
+        return Ok(());
+    }
+
+    let mut zip_reader = BufReader::new(File::open(path).await?);
     let mut zip_reader = ZipFileReader::with_tokio(&mut zip_reader).await?;
-    let mut zip_reader = zip_reader.reader_with_entry(0).await?;
+    let entry_name = Path::new(path)
+        .file_stem()
+        .map(|stem| format!("{}.txt", stem.to_string_lossy()));
+    let entries = zip_reader.file().entries();
+    let get_filename = |index: usize| entries[index].filename().as_str().unwrap_or_default();
+    let index = (0..entries.len())
+        .find(|index| entry_name.as_deref() == Some(get_filename(*index)))
+        .or_else(|| (0..entries.len()).find(|index| !get_filename(*index).starts_with("readme")))
+        .unwrap_or_default();
+    let mut entry_reader = zip_reader.reader_with_entry(index).await?;
+    let mut reader = AsyncBufReader::new(&mut entry_reader);
+    while reader.read_line(&mut line).await? > 0 {
+        f(line.trim_end_matches(['\r', '\n']));
+        line.clear();
+    }
 
-    let mut buffer = Vec::new();
-    zip_reader.read_to_end(&mut buffer).await?;
-    let mut file =
-        BufWriter::new(File::create(format!("{}.txt", &zip_path[..zip_path.len() - 4])).await?);
-    file.write_all(&buffer).await?;
-    file.flush().await?;
+    if entry_reader.compute_hash() != entry_reader.entry().crc32() {
+        let err = format!("CRC32 mismatch while reading {path}");
+        tracing::error!(err);
+        return Err(anyhow!(err));
+    }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn downloads_zip_again_only_when_changed() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/dump.zip", server.url());
+        let dir = tempdir().unwrap();
+        let zip_path = dir.path().join("dump.zip");
+        let zip_path = zip_path.to_str().unwrap();
+
+        let first = server
+            .mock("GET", "/dump.zip")
+            .match_header("if-none-match", Matcher::Missing)
+            .with_header("etag", "\"v1\"")
+            .with_body("first")
+            .expect(1)
+            .create_async()
+            .await;
+        zip_from_url(&Client::new(), &url, zip_path).await.unwrap();
+        first.assert_async().await;
+        assert_eq!(read_to_string(zip_path).await.unwrap(), "first");
+
+        let unchanged = server
+            .mock("GET", "/dump.zip")
+            .match_header("if-none-match", "\"v1\"")
+            .with_status(304)
+            .expect(1)
+            .create_async()
+            .await;
+        zip_from_url(&Client::new(), &url, zip_path).await.unwrap();
+        unchanged.assert_async().await;
+        assert_eq!(read_to_string(zip_path).await.unwrap(), "first");
+
+        unchanged.remove_async().await;
+        let changed = server
+            .mock("GET", "/dump.zip")
+            .match_header("if-none-match", "\"v1\"")
+            .with_header("etag", "\"v2\"")
+            .with_body("second")
+            .expect(1)
+            .create_async()
+            .await;
+        zip_from_url(&Client::new(), &url, zip_path).await.unwrap();
+        changed.assert_async().await;
+        assert_eq!(read_to_string(zip_path).await.unwrap(), "second");
+        assert_eq!(
+            read_to_string(format!("{zip_path}.validator"))
+                .await
+                .unwrap(),
+            "\"v2\""
+        );
+    }
+}